@@ -1,219 +1,6403 @@
+//! Generates the variants of ranges of discriminators in enums with the [`macro@enum_range`] attribute macro,
+//! or checks the ranges of existing variants with the [`EnumRange`](derive@EnumRange) derive.
+//!
+//! # Ranges
+//!
+//! The `#[range(...)]` attribute on a variant of the enum defines a range of variants:
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range]
+//! #[repr(u8)]
+//! enum RangedEnum {
+//!     NormalVariant = 1,
+//!     #[range(
+//!         format = "WellKnown{index}",
+//!         start = 206,
+//!         end = 210,
+//!         range_check = "is_well_known"
+//!     )]
+//!     RangeVariant,
+//!     OtherNormalVariant = 211,
+//! }
+//! ```
+//!
+//! The bounds can also be given with the range syntax, `start..=end` or `start..end` to exclude `end`,
+//! as the first argument: `#[range(206..=210, format = "WellKnown{index}")]`
+//!
+//! Parameters:
+//! - `start`: the first variant discriminator value in the range (start is included).
+//!   When it is omitted the range starts right after the previous variant, as a variant without a discriminator would
+//!   (0 for the first variant). It must then be possible to know the value of the previous variant from the enum.
+//!   Integers can be written in any of the Rust notations (e.g. `0x10`, `0o20`, `0b1_0000` or `1_000`).
+//!   It can also be a character literal (e.g. `'A'`), the discriminator value is then its code point,
+//!   or a constant plus or minus integers (e.g. `BASE + 4` or `u8::MAX - 10`). The `end` and the `skip` values must then
+//!   be relative to the same constant, and the `{value}` placeholder can't be used since only the compiler knows it
+//! - `end`: the last variant discriminator value in the range (end is included), it must be greater than or equal to `start`
+//!   unless the range is `descending`. Both `start` and `end` must fit in the repr of the enum and be of the same kind
+//!   (integers or characters)
+//! - `end_exclusive` (optional): excludes `end` from the range, with `start = 0, end = 16, end_exclusive`
+//!   the variants have the values 0 to 15. It can't be used with `count`
+//! - `ranges` (optional): a list of intervals making the range, as an alternative to `start` and `end`,
+//!   e.g. `ranges = [(10..=20), (30..40)]` (the end of an interval is excluded with `..`). The `{index}` placeholder
+//!   numbers the variants across all the intervals, and `range_check` is true for any of them
+//! - `count` (optional): the number of values in the range, as an alternative to `end` which is then `start + count - 1`
+//!   (`start - count + 1` if the range is `descending`). Exactly one of `end` and `count` must be given
+//! - `step` (optional): the difference between two consecutive variant discriminator values (default is 1).
+//!   With `start = 0, end = 32, step = 4` the variants have the values 0, 4, 8, ..., 32
+//! - `skip` (optional): a list of values which don't get a variant, e.g. `skip = [3, 7]`.
+//!   The variants are indexed without them and `range_check` returns `false` for them
+//! - `descending` (optional): generates the variants from `start` down to `end`, `start` must then be greater than or equal to `end`.
+//!   With `start = 10, end = 1, descending` the variants have the values 10, 9, ..., 1 while their index goes from 0 to 9
+//! - `format` (optional): the format used for naming the different variants.
+//!   `{name}` is replaced by the name of the annotated variant (here `RangeVariant`)
+//!   `{index}` is replaced by the index of the variant in the defined range (here 0-4)
+//!   `{value}` is replaced by the value of the variant in the defined range (here 206-210), or by its character for
+//!   ranges of characters. As not all characters are valid in identifiers, `{value}` should then follow a prefix
+//!   `{repr}` is replaced by the numerical repr of the enum (here `u8`)
+//!   `{parity}` is replaced by `Even` or `Odd` depending on the value of the variant, e.g. for register pairs
+//!   `{index}` and `{value}` accept a radix spec: `{value:x}` (lowercase hex), `{value:X}` (uppercase hex),
+//!   `{value:o}` (octal) and `{value:b}` (binary). Only the digits are emitted, without any `0x` prefix.
+//!   A negative value is written as `M` (minus) followed by the digits of its magnitude, e.g. `{value}` gives `M3`
+//!   for -3 and `{value:x}` gives `M1a` for -26, since `-` isn't allowed in identifiers. The `display` and `doc`
+//!   templates write it as `-3` and `-1a`
+//!   They can also be zero-padded to a fixed width: `{index:03}` gives `000`, `001`, ... and `{value:04X}` gives `00CE`,
+//!   the width can be up to 128
+//!   The radix can also be named by the placeholder: `{value_hex}`, `{value_hex_upper}`, `{value_oct}`, `{value_bin}`
+//!   and the same for `{index}`, e.g. `{index_hex}`. They take a width, `{value_hex_upper:04}` is `{value:04X}`
+//!   The default value is the `format` of `#[enum_range(...)]`, or `"{name}{index}"` if it doesn't have one
+//! - `prefix` and `suffix` (optional): texts put before and after the formatted names, e.g. `prefix = "Ch"`.
+//!   Without a `format`, the name of a variant is then only its value wrapped by them (`Ch0`, `Ch1`, ...),
+//!   or its index for a range relative to a constant
+//! - `index_offset` (optional): a number added to the index of the variants in the `{index}` placeholder (default is 0),
+//!   with `index_offset = 1` the `{index}` of the first variant is 1. It doesn't change the `{value}` placeholder
+//! - `value_base` and `value_scale` (optional): the `{value}` placeholder becomes `value_base + index * value_scale`
+//!   when one of them is given (`value_base` defaults to 0 and `value_scale` to 1), e.g. to name the variants with
+//!   the address of a register. The discriminators of the variants are still the values of the range
+//! - `names` (optional): explicit names for the first variants of the range, e.g. `names = ["Off", "Low"]`.
+//!   The variants without a name in the list are named with `format`. There can't be more names than variants
+//! - `case` (optional): changes the case of the variant names once they are formatted, e.g. for `FooBar1` either
+//!   `"upper"` (`FOOBAR1`) or `"lower"` (`foobar1`), which only change the case of the letters, `"pascal"` (`FooBar1`)
+//!   or `"snake"` (`foo_bar1`), which split the words on the underscores and the uppercase letters
+//! - `range_check` (optional): the name of the method used to check if an enum variant is in the defined range (here `RangedEnum::is_well_known`).
+//!   If `range_check` is not specified the method is not generated.
+//!   The method takes `&self`, so the enum doesn't need to be `Copy`.
+//!   It is marked `#[inline]`, like the other checks and accessors, so it can be inlined across crates.
+//!   When `range_check` is given, a const array of all the variants in the range is also generated,
+//!   named after the method in uppercase without its `is_` prefix (here `RangedEnum::WELL_KNOWN_VARIANTS`),
+//!   along with the consts of the lowest and highest values of the range (here `RangedEnum::WELL_KNOWN_MIN`
+//!   and `RangedEnum::WELL_KNOWN_MAX`) and the const of the number of variants in the range (here `RangedEnum::WELL_KNOWN_COUNT`)
+//! - `const_prefix` (optional): the prefix of the names of these consts instead of the uppercase `range_check` name
+//! - `by_value` (optional): makes the `range_check` method take `self` instead of `&self`
+//! - `trait` (optional, also spelled `range_trait`): the path of a trait declaring the `range_check` method, e.g. `trait = "WellKnownRange"`.
+//!   The method is then implemented in an `impl WellKnownRange for RangedEnum` instead of the inherent impl, without
+//!   `vis` nor `const_fns`. The trait is defined by the user, and only one range of the enum can implement it
+//! - `value_check` (optional): set to `false` to not generate the associated function doing the `range_check`
+//!   on a raw repr value, named after the method with a `_value` suffix (here `RangedEnum::is_well_known_value`)
+//! - `range_check_bounds` (optional): which of the lowest and highest values of the range `range_check` includes,
+//!   either `"inclusive"` (the default), `"exclusive"`, `"inclusive_lower"` or `"inclusive_upper"`.
+//!   The bounds of each interval of `ranges` are then excluded. It doesn't change the generated variants
+//! - `bounds_fn` (optional): the name of the associated function returning the bounds of the range as a `RangeInclusive`
+//!   of the repr, from its lowest to its highest value. It is generated along with the `range_check` method and defaults
+//!   to its name without the `is_` prefix followed by `_bounds` (here `RangedEnum::well_known_bounds`)
+//! - `iter_fn` (optional): the name of the method returning an iterator over the variants of the range.
+//!   It is generated along with the `range_check` method and defaults to its name without the `is_` prefix
+//!   followed by `_iter` (here `RangedEnum::well_known_iter`)
+//!
+//!   Methods returning the next and previous variants of the range in the order they are generated are also
+//!   generated along with `range_check` (here `RangedEnum::well_known_next` and `RangedEnum::well_known_prev`),
+//!   they return `None` past the last and before the first variant of the range. Like `range_check`, they take
+//!   `self` by value when `by_value` is set.
+//!   The associated function clamping a raw value to a variant of the range is generated as well when the enum has
+//!   a numerical repr (here `RangedEnum::clamp_to_well_known`). The values below the range give its lowest variant,
+//!   the ones above it its highest, and the ones in between the variant with the highest value not above them
+//! - `vis` (optional): the visibility of the items generated along with `range_check`,
+//!   e.g. `vis = "pub(crate)"`. It defaults to the `vis` of `#[enum_range(...)]`, which itself defaults to `pub`
+//! - `doc` (optional): the doc comment of the generated variants, using the same placeholders as `format`.
+//!   It replaces the doc comments of the annotated variant, which are otherwise copied to every generated variant
+//!   along with its other attributes (e.g. `#[cfg(...)]`)
+//! - `display` (optional): the text written by the `Display` implementation of the generated variants when
+//!   the enum uses `#[enum_range(display)]`, using the same placeholders as `format`. The default is the variant name
+//! - `label` (optional): the name of the range returned by the `which_range` method of the enum.
+//!   It defaults to the name of the `range_check` method without its `is_` prefix (here `"well_known"`),
+//!   or to the name of the annotated variant if there is no `range_check`
+//! - `as_bit` (optional): treats the values of the range as bit positions. The enum then gets the `bit_mask` method
+//!   returning `1 << position` for the variants of the range (0 for the other variants), and the `from_mask`
+//!   associated function returning the variant of a mask with a single bit set. Only one range of the enum can set it,
+//!   its values must be integers fitting in the bit width of the numerical repr attribute the enum requires then
+//! - `variants` (optional): set to `false` to only generate the `range_check` items checking the values, without
+//!   generating the variants of the range. The annotated variant then stays as it is, taking the value following the
+//!   previous variant, and the items listing the variants of the range (the const array of the variants, their count,
+//!   the iterator, navigation and clamping methods) aren't generated. It requires a `range_check` and a numerical repr,
+//!   and isn't limited by `max_variants`
+//! - `checker_only` (optional): like `variants = false`, except that the annotated variant is removed. The range is then
+//!   only a view over the values of the enum, which can overlap the other ranges, e.g. values both reserved and experimental.
+//!   It can't be used by `#[derive(EnumRange)]`, which can't remove variants
+//! - `default` (optional): implements `Default` for the enum, returning the first generated variant of the range.
+//!   Only one range of the enum can be the default, and its variants can't have fields
+//! - `default_value` (optional): the value of the variant returned by `Default` instead of the first one,
+//!   it must be a value of the range and implies `default`
+//!
+//! The placeholders can be combined in any order:
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range]
+//! #[repr(u8)]
+//! enum NamedEnum {
+//!     #[range(start = 0, end = 2)]
+//!     Default,
+//!     #[range(format = "R{index}_{value}_{name}", start = 10, end = 12)]
+//!     Reversed,
+//! }
+//!
+//! assert_eq!(NamedEnum::Default2 as u8, 2);
+//! assert_eq!(NamedEnum::R2_12_Reversed as u8, 12);
+//! ```
+//!
+//! The bounds can be relative to a constant:
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! const BASE: u8 = 0x40;
+//!
+//! #[enum_range]
+//! #[repr(u8)]
+//! enum Relative {
+//!     #[range(format = "Channel{index}", start = BASE, end = BASE + 15, skip = [BASE + 2], range_check = "is_channel")]
+//!     Channel,
+//!     #[range(format = "Top{index}", start = u8::MAX - 3, count = 4, range_check = "is_top")]
+//!     Top,
+//! }
+//!
+//! assert_eq!(Relative::Channel0 as u8, 0x40);
+//! assert_eq!(Relative::Channel14 as u8, 0x4F);
+//! assert!(Relative::is_channel_value(0x41));
+//! assert!(!Relative::is_channel_value(0x42));
+//! assert_eq!(Relative::Top3 as u8, 255);
+//! assert_eq!(Relative::top_bounds(), 252..=255);
+//! ```
+//!
+//! The values relative to a constant are checked by a compile time assertion, failing when they don't fit in the repr.
+//! The discriminators are computed with wrapping arithmetic, so the assertion is the only error the compiler reports:
+//!
+//! ```compile_fail
+//! use enum_range::enum_range;
+//!
+//! const BASE: u8 = 250;
+//!
+//! #[enum_range]
+//! #[repr(u8)]
+//! enum Overflowing {
+//!     #[range(format = "Channel{index}", start = BASE, end = BASE + 10)]
+//!     Channel,
+//! }
+//! ```
+//!
+//! A range can be made of multiple intervals, its variants are indexed across all of them:
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range]
+//! #[repr(u8)]
+//! enum Split {
+//!     Normal = 25,
+//!     #[range(ranges = [(10..=20), (30..40)], format = "Res{index}_{value}", range_check = "is_reserved")]
+//!     Reserved,
+//! }
+//!
+//! assert_eq!(Split::Res10_20 as u8, 20);
+//! assert_eq!(Split::Res11_30 as u8, 30);
+//! assert_eq!(Split::Res20_39 as u8, 39);
+//! assert!(Split::is_reserved_value(35));
+//! assert!(!Split::is_reserved_value(25));
+//! assert!(!Split::Normal.is_reserved());
+//! ```
+//!
+//! The range syntax is a shorthand for `start` and `end`:
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range]
+//! #[repr(i8)]
+//! enum Shorthand {
+//!     #[range(0..=3, format = "Inclusive{value}")]
+//!     Inclusive,
+//!     #[range(-8..-4, format = "Exclusive{index}", range_check = "is_exclusive")]
+//!     Exclusive,
+//!     #[range('a'..'c', format = "Letter_{value}")]
+//!     Letter,
+//! }
+//!
+//! assert_eq!(Shorthand::Inclusive3 as i8, 3);
+//! assert_eq!(Shorthand::Exclusive0 as i8, -8);
+//! assert_eq!(Shorthand::Exclusive3 as i8, -5);
+//! assert!(!Shorthand::is_exclusive_value(-4));
+//! assert_eq!(Shorthand::Letter_b as i8, 'b' as i8);
+//! ```
+//!
+//! The end of a range can be excluded:
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range]
+//! #[repr(u8)]
+//! enum HalfOpen {
+//!     #[range(start = 0, end = 16, end_exclusive, range_check = "is_nibble")]
+//!     Nibble,
+//!     Sixteen = 16,
+//! }
+//!
+//! assert_eq!(HalfOpen::Nibble15 as u8, 15);
+//! assert!(HalfOpen::is_nibble_value(15));
+//! assert!(!HalfOpen::is_nibble_value(16));
+//! assert!(!HalfOpen::Sixteen.is_nibble());
+//! ```
+//!
+//! The indexes can start from another number than 0:
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range]
+//! #[repr(u8)]
+//! enum Midi {
+//!     #[range(format = "Channel{index}", start = 0x40, end = 0x4F, index_offset = 1)]
+//!     Channel,
+//! }
+//!
+//! assert_eq!(Midi::Channel1 as u8, 0x40);
+//! assert_eq!(Midi::Channel16 as u8, 0x4F);
+//! ```
+//!
+//! The names can hold a value computed from the index, such as an address:
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range]
+//! #[repr(u8)]
+//! enum Register {
+//!     #[range(format = "Reg{value:x}", start = 0, end = 3, value_base = 0x1000, value_scale = 4)]
+//!     Reg,
+//! }
+//!
+//! assert_eq!(Register::Reg1000 as u8, 0);
+//! assert_eq!(Register::Reg1004 as u8, 1);
+//! assert_eq!(Register::Reg100c as u8, 3);
+//! ```
+//!
+//! The repr can be part of the names:
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range]
+//! #[repr(u16)]
+//! #[allow(non_camel_case_types)]
+//! enum Storage {
+//!     #[range(format = "{repr}_{value}", start = 206, end = 207)]
+//!     Storage,
+//! }
+//!
+//! assert_eq!(Storage::u16_206 as u16, 206);
+//! ```
+//!
+//! The first variants can be given explicit names:
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range]
+//! #[repr(u8)]
+//! enum Level {
+//!     #[range(start = 0, end = 5, names = ["Off", "Low", "Med", "High"], format = "Level{value}")]
+//!     Level,
+//! }
+//!
+//! assert_eq!(Level::Off as u8, 0);
+//! assert_eq!(Level::High as u8, 3);
+//! assert_eq!(Level::Level4 as u8, 4);
+//! assert_eq!(Level::Level5 as u8, 5);
+//! ```
+//!
+//! The names can be wrapped by a prefix and a suffix:
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range]
+//! #[repr(u8)]
+//! enum Mixer {
+//!     #[range(start = 0, end = 9, prefix = "Ch")]
+//!     Channel,
+//!     #[range(format = "{index}", start = 10, end = 11, prefix = "Bus", suffix = "Out")]
+//!     Bus,
+//! }
+//!
+//! assert_eq!(Mixer::Ch0 as u8, 0);
+//! assert_eq!(Mixer::Ch9 as u8, 9);
+//! assert_eq!(Mixer::Bus1Out as u8, 11);
+//! ```
+//!
+//! The case of the names can be changed once they are formatted. The generated variants whose name isn't in
+//! camel case are allowed to be so, without allowing it on the whole enum:
+//!
+//! ```rust
+//! #![deny(non_camel_case_types)]
+//! use enum_range::enum_range;
+//!
+//! #[enum_range]
+//! #[repr(u8)]
+//! enum Command {
+//!     #[range(start = 0, end = 1, names = ["GET_STATUS", "set_mode"], case = "pascal")]
+//!     Named,
+//!     #[range(format = "{name}{index}", start = 2, end = 3, case = "snake")]
+//!     ResetDevice,
+//!     #[range(format = "{name}_{index}", start = 4, end = 4, case = "upper")]
+//!     Vendor,
+//!     #[range(format = "_{index}Raw", start = 5, end = 5, case = "snake")]
+//!     Raw,
+//! }
+//!
+//! assert_eq!(Command::GetStatus as u8, 0);
+//! assert_eq!(Command::SetMode as u8, 1);
+//! assert_eq!(Command::reset_device1 as u8, 3);
+//! assert_eq!(Command::VENDOR_0 as u8, 4);
+//! assert_eq!(Command::_0_raw as u8, 5);
+//! ```
+//!
+//! The `upper` and `lower` cases keep the words of the names together, unlike `snake`:
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range]
+//! #[repr(u8)]
+//! enum Cases {
+//!     #[range(names = ["FooBar1"], start = 0, end = 0, case = "upper")]
+//!     Upper,
+//!     #[range(names = ["FooBar1"], start = 1, end = 1, case = "lower")]
+//!     Lower,
+//!     #[range(names = ["foo_bar1"], start = 2, end = 2, case = "pascal")]
+//!     Pascal,
+//!     #[range(names = ["FooBar1"], start = 3, end = 3, case = "snake")]
+//!     Snake,
+//! }
+//!
+//! assert_eq!(Cases::FOOBAR1 as u8, 0);
+//! assert_eq!(Cases::foobar1 as u8, 1);
+//! assert_eq!(Cases::FooBar1 as u8, 2);
+//! assert_eq!(Cases::foo_bar1 as u8, 3);
+//! ```
+//!
+//! The names which are keywords are generated as raw identifiers, their text stays the keyword:
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range(as_str)]
+//! #[repr(u8)]
+//! #[allow(non_camel_case_types)]
+//! enum Keyword {
+//!     #[range(start = 0, end = 2, names = ["type", "match"], format = "{name}{index}", case = "lower")]
+//!     Fn,
+//! }
+//!
+//! assert_eq!(Keyword::r#type as u8, 0);
+//! assert_eq!(Keyword::r#match as u8, 1);
+//! assert_eq!(Keyword::fn2 as u8, 2);
+//! assert_eq!(Keyword::r#type.as_str(), "type");
+//! ```
+//!
+//! The names must be valid identifiers, a `{value}` without a prefix gives names starting with a digit:
+//!
+//! ```compile_fail
+//! use enum_range::enum_range;
+//!
+//! #[enum_range]
+//! #[repr(u8)]
+//! enum Code {
+//!     #[range(format = "{value}", start = 200, end = 206)]
+//!     Code,
+//! }
+//! ```
+//!
+//! As well as names with characters which are not allowed in identifiers:
+//!
+//! ```compile_fail
+//! use enum_range::enum_range;
+//!
+//! #[enum_range]
+//! #[repr(u8)]
+//! enum Code {
+//!     #[range(names = ["Code²", "Code³"], start = 2, end = 3)]
+//!     Code,
+//! }
+//! ```
+//!
+//! A range can be defined with characters, the generated variants then represent their code point:
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range]
+//! #[repr(u32)]
+//! enum Letter {
+//!     #[range(format = "{name}{value}", start = 'A', end = 'Z', range_check = "is_upper")]
+//!     Upper,
+//!     #[range(format = "Lower{value:x}", start = 'a', end = 'c')]
+//!     Lower,
+//! }
+//!
+//! assert_eq!(Letter::UpperA as u32, 'A' as u32);
+//! assert_eq!(Letter::UpperZ as u32, 'Z' as u32);
+//! assert!(Letter::UpperQ.is_upper());
+//! assert_eq!(Letter::Lower61 as u32, 'a' as u32);
+//! ```
+//!
+//! The characters written by `{value}` must be allowed in identifiers, other ones can be written as numbers instead:
+//!
+//! ```compile_fail
+//! use enum_range::enum_range;
+//!
+//! #[enum_range]
+//! #[repr(u32)]
+//! enum Superscript {
+//!     #[range(format = "Sup{value}", start = '²', end = '³')]
+//!     Sup,
+//! }
+//! ```
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range]
+//! #[repr(u32)]
+//! enum Superscript {
+//!     #[range(format = "Sup{value:x}", start = '²', end = '³')]
+//!     Sup,
+//! }
+//!
+//! assert_eq!(Superscript::Supb2 as u32, '²' as u32);
+//! ```
+//!
+//! The values can be written in another radix:
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range]
+//! #[repr(u8)]
+//! enum Opcode {
+//!     #[range(format = "Opcode{value:X}", start = 30, end = 31)]
+//!     Upper,
+//!     #[range(format = "Opcode{value:x}h", start = 0xAB, end = 0xAC)]
+//!     Lower,
+//!     #[range(format = "Opcode{value:o}o", start = 8, end = 9)]
+//!     Octal,
+//!     #[range(format = "Opcode{value:b}b", start = 5, end = 6)]
+//!     Binary,
+//! }
+//!
+//! assert_eq!(Opcode::Opcode1F as u8, 31);
+//! assert_eq!(Opcode::Opcodeabh as u8, 0xAB);
+//! assert_eq!(Opcode::Opcode11o as u8, 9);
+//! assert_eq!(Opcode::Opcode101b as u8, 5);
+//! ```
+//!
+//! Padding the placeholders keeps the variants sorted by name:
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range]
+//! #[repr(u16)]
+//! enum Padded {
+//!     #[range(format = "Foo{index:03}", start = 0, end = 10)]
+//!     Foo,
+//!     #[range(format = "Bar{value:04X}_{index}", start = 0xCE, end = 0xCF)]
+//!     Bar,
+//! }
+//!
+//! assert_eq!(Padded::Foo000 as u16, 0);
+//! assert_eq!(Padded::Foo010 as u16, 10);
+//! assert_eq!(Padded::Bar00CE_0 as u16, 0xCE);
+//! assert_eq!(Padded::Bar00CF_1 as u16, 0xCF);
+//! ```
+//!
+//! ```compile_fail
+//! use enum_range::enum_range;
+//!
+//! #[enum_range]
+//! #[repr(u16)]
+//! enum Padded {
+//!     #[range(format = "Foo{index:099999999999999999999999}", start = 0, end = 10)]
+//!     Foo,
+//! }
+//! ```
+//!
+//! The radix can be named instead of given by a spec:
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range]
+//! #[repr(u16)]
+//! enum Named {
+//!     #[range(format = "Reg{value_hex_upper:04}", start = 0xCE, end = 0xCF)]
+//!     Reg,
+//!     #[range(format = "Port{value_hex}_{index_bin}", start = 0x1A, end = 0x1B)]
+//!     Port,
+//!     #[range(format = "Mode{value_oct}", start = 8, end = 8)]
+//!     Mode,
+//! }
+//!
+//! assert_eq!(Named::Reg00CE as u16, 0xCE);
+//! assert_eq!(Named::Port1b_1 as u16, 0x1B);
+//! assert_eq!(Named::Mode10 as u16, 8);
+//! ```
+//!
+//! The `{parity}` placeholder tells the even values from the odd ones:
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range]
+//! #[repr(u8)]
+//! enum Register {
+//!     #[range(format = "R{index}{parity}", start = 0x10, end = 0x13)]
+//!     Pair,
+//! }
+//!
+//! assert_eq!(Register::R0Even as u8, 0x10);
+//! assert_eq!(Register::R1Odd as u8, 0x11);
+//! assert_eq!(Register::R3Odd as u8, 0x13);
+//! ```
+//!
+//! A format must generate a distinct name for each variant of the range:
+//!
+//! ```compile_fail
+//! use enum_range::enum_range;
+//!
+//! #[enum_range]
+//! #[repr(u8)]
+//! enum Duplicated {
+//!     #[range(format = "Same", start = 0, end = 2)]
+//!     Same,
+//! }
+//! ```
+//!
+//! `start` and `end` can be negative when the enum has a signed repr:
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range]
+//! #[repr(i8)]
+//! #[derive(Clone, Copy)]
+//! enum SignedEnum {
+//!     #[range(format = "Neg{index}", start = -5, end = -2, range_check = "is_neg")]
+//!     Neg,
+//!     Zero = 0,
+//! }
+//!
+//! assert_eq!(SignedEnum::Neg0 as i8, -5);
+//! assert_eq!(SignedEnum::Neg3 as i8, -2);
+//! assert!(SignedEnum::Neg0.is_neg());
+//! assert!(SignedEnum::Neg3.is_neg());
+//! assert!(!SignedEnum::Zero.is_neg());
+//! assert_eq!(SignedEnum::NEG_VARIANTS.len(), 4);
+//! assert_eq!(SignedEnum::NEG_VARIANTS[1] as i8, -4);
+//! ```
+//!
+//! The negative values are written with a leading `M` in the names:
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range(display)]
+//! #[repr(i8)]
+//! enum Offset {
+//!     #[range(format = "Neg{value}", start = -3, end = -1, display = "offset {value}")]
+//!     Neg,
+//!     #[range(format = "Hex{value:02X}", start = -26, end = -26)]
+//!     Hex,
+//!     Zero = 0,
+//! }
+//!
+//! assert_eq!(Offset::NegM3 as i8, -3);
+//! assert_eq!(Offset::NegM1 as i8, -1);
+//! assert_eq!(Offset::HexM1A as i8, -26);
+//! assert_eq!(Offset::NegM2.to_string(), "offset -2");
+//! ```
+//!
+//! The values can use the whole span of 64 bits reprs, and of 128 bits reprs up to `i128::MAX`:
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range]
+//! #[repr(u64)]
+//! #[derive(Clone, Copy)]
+//! enum Wide {
+//!     #[range(start = 4294967296, end = 4294967298, range_check = "is_above_u32")]
+//!     AboveU32,
+//!     #[range(start = 18446744073709551614, end = 18446744073709551615)]
+//!     Top,
+//! }
+//!
+//! assert_eq!(Wide::AboveU320 as u64, u32::MAX as u64 + 1);
+//! assert_eq!(Wide::Top1 as u64, u64::MAX);
+//! assert!(Wide::AboveU322.is_above_u32());
+//! assert!(!Wide::Top0.is_above_u32());
+//! ```
+//!
+//! A `descending` range counts down from `start` to `end`:
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range]
+//! #[repr(u8)]
+//! #[derive(Clone, Copy)]
+//! enum Countdown {
+//!     #[range(format = "T{index}", start = 10, end = 1, descending, range_check = "is_countdown")]
+//!     Countdown,
+//!     Liftoff = 0,
+//! }
+//!
+//! assert_eq!(Countdown::T0 as u8, 10);
+//! assert_eq!(Countdown::T9 as u8, 1);
+//! assert!(Countdown::T9.is_countdown());
+//! assert!(!Countdown::Liftoff.is_countdown());
+//! ```
+//!
+//! A range can be declared with its length instead of its `end`:
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range]
+//! #[repr(u8)]
+//! enum Channels {
+//!     #[range(format = "Channel{index}", start = 0x40, count = 16)]
+//!     Channel,
+//! }
+//!
+//! assert_eq!(Channels::Channel0 as u8, 0x40);
+//! assert_eq!(Channels::Channel15 as u8, 0x4F);
+//! ```
+//!
+//! Without a `start`, a range follows the previous variant:
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! const BASE: u8 = 0x80;
+//!
+//! #[enum_range]
+//! #[repr(u8)]
+//! enum Registers {
+//!     Status = 4,
+//!     #[range(format = "Data{index}", count = 3)]
+//!     Data,
+//!     Control,
+//!     #[range(format = "Mapped{index}", start = BASE, end = BASE + 1)]
+//!     Mapped,
+//!     #[range(format = "Extra{index}", count = 2)]
+//!     Extra,
+//! }
+//!
+//! assert_eq!(Registers::Data0 as u8, 5);
+//! assert_eq!(Registers::Data2 as u8, 7);
+//! assert_eq!(Registers::Control as u8, 8);
+//! assert_eq!(Registers::Extra0 as u8, 0x82);
+//! ```
+//!
+//! The annotated variant can't have a discriminator since the range gives the values of its variants:
+//!
+//! ```compile_fail
+//! use enum_range::enum_range;
+//!
+//! #[enum_range]
+//! #[repr(u8)]
+//! enum Registers {
+//!     #[range(format = "Data{index}", start = 10, end = 20)]
+//!     Data = 99,
+//! }
+//! ```
+//!
+//! The start can't be inferred when the value of the previous variant isn't known:
+//!
+//! ```compile_fail
+//! use enum_range::enum_range;
+//!
+//! const BASE: u8 = 0x80;
+//!
+//! #[enum_range]
+//! #[repr(u8)]
+//! enum Registers {
+//!     Status = BASE,
+//!     #[range(format = "Data{index}", count = 3)]
+//!     Data,
+//! }
+//! ```
+//!
+//! A range still needs an end or a count:
+//!
+//! ```compile_fail
+//! use enum_range::enum_range;
+//!
+//! #[enum_range]
+//! #[repr(u8)]
+//! enum Registers {
+//!     Status = 4,
+//!     #[range(start = 1)]
+//!     Data,
+//! }
+//! ```
+//!
+//! The bounds are integer literals as in Rust code:
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range]
+//! #[repr(u32)]
+//! enum Notations {
+//!     #[range(format = "Hex{value:X}", start = 0x10, end = 0x1F)]
+//!     Hex,
+//!     #[range(format = "Oct{index}", start = 0o40, end = 0b10_0001)]
+//!     Oct,
+//!     #[range(format = "Big{index}", start = 0x1_0000, end = 65_537)]
+//!     Big,
+//! }
+//!
+//! assert_eq!(Notations::Hex10 as u32, 16);
+//! assert_eq!(Notations::Hex1F as u32, 31);
+//! assert_eq!(Notations::Oct1 as u32, 33);
+//! assert_eq!(Notations::Big0 as u32, 65_536);
+//! assert_eq!(Notations::Big1 as u32, 65_537);
+//! ```
+//!
+//! Some values of a range can be skipped:
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range]
+//! #[repr(u8)]
+//! #[derive(Clone, Copy)]
+//! enum Holes {
+//!     #[range(format = "Id{index}_{value}", start = 0, end = 10, skip = [3, 7], range_check = "is_id")]
+//!     Id,
+//!     Reserved3 = 3,
+//! }
+//!
+//! assert_eq!(Holes::Id2_2 as u8, 2);
+//! assert_eq!(Holes::Id3_4 as u8, 4);
+//! assert_eq!(Holes::Id8_10 as u8, 10);
+//! assert!(Holes::Id3_4.is_id());
+//! assert!(!Holes::Reserved3.is_id());
+//! assert_eq!(Holes::ID_COUNT, 9);
+//! ```
+//!
+//! The variants of a range can be iterated over in the order they are generated:
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range]
+//! #[repr(u8)]
+//! #[derive(Debug, PartialEq)]
+//! enum RangedEnum {
+//!     NormalVariant = 1,
+//!     #[range(format = "WellKnown{index}", start = 206, end = 208, range_check = "is_well_known")]
+//!     WellKnown,
+//!     #[range(start = 220, end = 221, range_check = "is_other", iter_fn = "others")]
+//!     Other,
+//! }
+//!
+//! let well_known: Vec<_> = RangedEnum::well_known_iter().collect();
+//! assert_eq!(
+//!     well_known,
+//!     [RangedEnum::WellKnown0, RangedEnum::WellKnown1, RangedEnum::WellKnown2]
+//! );
+//! assert_eq!(RangedEnum::others().last(), Some(RangedEnum::Other1));
+//! ```
+//!
+//! ```compile_fail
+//! use enum_range::enum_range;
+//!
+//! #[enum_range]
+//! #[repr(u8)]
+//! enum RangedEnum {
+//!     #[range(start = 220, end = 221, range_check = "is_other", iter_fn = "other values")]
+//!     Other,
+//! }
+//! ```
+//!
+//! The `range_check` method borrows the variant, so it works on enums which are not `Copy`:
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range]
+//! #[repr(u8)]
+//! enum NotCopy {
+//!     #[range(start = 1, end = 3, range_check = "is_low")]
+//!     Low,
+//!     #[range(start = 4, end = 6, range_check = "is_high", by_value)]
+//!     High,
+//! }
+//!
+//! let low = NotCopy::Low0;
+//! assert!(low.is_low());
+//! assert!(!low.is_high());
+//! assert!(NotCopy::High2.is_high());
+//! ```
+//!
+//! The `range_check` method can implement a trait, to check the variants of several enums in generic code:
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! trait WellKnownRange {
+//!     fn is_well_known(&self) -> bool;
+//! }
+//!
+//! #[enum_range]
+//! #[repr(u8)]
+//! enum Ports {
+//!     #[range(start = 1, end = 3, range_check = "is_well_known", trait = "WellKnownRange")]
+//!     Port,
+//! }
+//!
+//! #[enum_range]
+//! #[repr(u16)]
+//! enum Codes {
+//!     Other = 0,
+//!     #[range(start = 200, end = 210, range_check = "is_well_known", trait = "WellKnownRange")]
+//!     Code,
+//! }
+//!
+//! fn count_well_known<T: WellKnownRange>(values: &[T]) -> usize {
+//!     values.iter().filter(|value| value.is_well_known()).count()
+//! }
+//!
+//! assert_eq!(count_well_known(&[Ports::Port1, Ports::Port2]), 2);
+//! assert_eq!(count_well_known(&[Codes::Other, Codes::Code0]), 1);
+//! assert!(Codes::is_well_known_value(205));
+//! ```
+//!
+//! A trait can only be implemented by one range of the enum:
+//!
+//! ```compile_fail
+//! use enum_range::enum_range;
+//!
+//! trait Check {
+//!     fn check(&self) -> bool;
+//! }
+//!
+//! #[enum_range]
+//! #[repr(u8)]
+//! enum Twice {
+//!     #[range(start = 1, end = 3, range_check = "check", trait = "Check")]
+//!     Low,
+//!     #[range(start = 10, end = 13, range_check = "check", trait = "Check")]
+//!     High,
+//! }
+//! ```
+//!
+//! A raw value can be checked without building a variant first:
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range]
+//! #[repr(u16)]
+//! enum Port {
+//!     Http = 80,
+//!     #[range(start = 8000, end = 8090, skip = [8080], range_check = "is_alternate")]
+//!     Alternate,
+//!     #[range(start = 49152, end = 49160, range_check = "is_dynamic", value_check = false)]
+//!     Dynamic,
+//! }
+//!
+//! assert!(Port::is_alternate_value(8000));
+//! assert!(!Port::is_alternate_value(8080));
+//! assert!(!Port::is_alternate_value(80));
+//! ```
+//!
+//! A range can only check the values without generating variants:
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range(from_repr)]
+//! #[repr(u16)]
+//! #[derive(Debug, PartialEq)]
+//! enum Port {
+//!     Http = 80,
+//!     #[range(start = 1024, end = 65535, range_check = "is_registered", variants = false)]
+//!     Registered,
+//!     #[range(format = "Alt{index}", start = 8080, end = 8081, range_check = "is_alternate")]
+//!     Alternate,
+//! }
+//!
+//! assert_eq!(Port::Registered as u16, 81);
+//! assert!(!Port::Registered.is_registered());
+//! assert!(Port::Alt1.is_registered());
+//! assert!(Port::is_registered_value(50000));
+//! assert_eq!(Port::registered_bounds(), 1024..=65535);
+//! assert_eq!(Port::from_repr(81), Some(Port::Registered));
+//! ```
+//!
+//! It needs a `range_check` to generate:
+//!
+//! ```compile_fail
+//! use enum_range::enum_range;
+//!
+//! #[enum_range]
+//! #[repr(u16)]
+//! enum Port {
+//!     #[range(start = 1024, end = 65535, variants = false)]
+//!     Registered,
+//! }
+//! ```
+//!
+//! A `checker_only` range removes the variant it annotates, so it can overlap the other ranges:
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range]
+//! #[repr(u8)]
+//! enum Registry {
+//!     Assigned = 0,
+//!     #[range(format = "Reserved{value}", start = 240, end = 255, range_check = "is_reserved")]
+//!     Reserved,
+//!     #[range(start = 250, end = 255, range_check = "is_experimental", checker_only)]
+//!     Experimental,
+//!     #[range(start = 0, end = 249, range_check = "is_stable", checker_only)]
+//!     Stable,
+//! }
+//!
+//! assert!(Registry::Reserved252.is_reserved());
+//! assert!(Registry::Reserved252.is_experimental());
+//! assert!(!Registry::Reserved245.is_experimental());
+//! assert!(Registry::Reserved245.is_stable());
+//! assert!(Registry::Assigned.is_stable());
+//! assert_eq!(Registry::experimental_bounds(), 250..=255);
+//! ```
+//!
+//! The `range_check` can leave out the lowest or the highest values of the range:
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! const BASE: u16 = 9000;
+//!
+//! #[enum_range]
+//! #[repr(u16)]
+//! enum Port {
+//!     #[range(start = 8000, end = 8010, range_check = "is_alternate", range_check_bounds = "exclusive")]
+//!     Alternate,
+//!     #[range(start = BASE, end = BASE + 10, range_check = "is_relative", range_check_bounds = "inclusive_lower")]
+//!     Relative,
+//!     #[range(start = 100, end = 100, range_check = "is_single", range_check_bounds = "inclusive_upper")]
+//!     Single,
+//! }
+//!
+//! assert!(!Port::Alternate0.is_alternate());
+//! assert!(Port::Alternate1.is_alternate());
+//! assert!(!Port::Alternate10.is_alternate());
+//! assert!(Port::is_relative_value(9000));
+//! assert!(!Port::is_relative_value(9010));
+//! assert!(!Port::Single0.is_single());
+//! assert_eq!(Port::alternate_bounds(), 8000..=8010);
+//! ```
+//!
+//! The bounds of a range can be given to anything taking a `RangeBounds`:
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range]
+//! #[repr(u16)]
+//! enum Port {
+//!     #[range(start = 8000, end = 8010, range_check = "is_alternate")]
+//!     Alternate,
+//!     #[range(start = 9010, end = 9000, descending, range_check = "is_down", bounds_fn = "down", const_prefix = "FALLING")]
+//!     Down,
+//! }
+//!
+//! assert_eq!(Port::alternate_bounds(), 8000..=8010);
+//! assert_eq!(Port::down(), 9000..=9010);
+//! assert_eq!((Port::ALTERNATE_MIN, Port::ALTERNATE_MAX), (8000, 8010));
+//! assert_eq!(Port::FALLING_VARIANTS.len(), 11);
+//! assert_eq!(Port::FALLING_MIN, 9000);
+//! assert_eq!(Port::ALTERNATE_COUNT, 11);
+//! let counters = [0u32; Port::FALLING_COUNT];
+//! assert_eq!(counters.len(), Port::FALLING_VARIANTS.len());
+//! let ports = [80, 8005, 9000];
+//! assert_eq!(ports.iter().filter(|port| Port::alternate_bounds().contains(port)).count(), 1);
+//! ```
+//!
+//! ```compile_fail
+//! use enum_range::enum_range;
+//!
+//! #[enum_range]
+//! #[repr(u16)]
+//! enum Port {
+//!     #[range(start = 8000, end = 8010, range_check = "is_alternate", const_prefix = "ALTERNATE PORT")]
+//!     Alternate,
+//! }
+//! ```
+//!
+//! ```compile_fail
+//! use enum_range::enum_range;
+//!
+//! #[enum_range]
+//! #[repr(u16)]
+//! enum Port {
+//!     #[range(start = 8000, end = 8010, range_check = "is_alternate", bounds_fn = "alternate-bounds")]
+//!     Alternate,
+//! }
+//! ```
+//!
+//! The variants of a range can be walked through with the navigation methods:
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range]
+//! #[repr(u8)]
+//! #[derive(Debug, PartialEq)]
+//! enum State {
+//!     Idle = 0,
+//!     #[range(format = "Step{value}", start = 10, end = 40, step = 10, range_check = "is_step")]
+//!     Step,
+//! }
+//!
+//! assert_eq!(State::Step10.step_next(), Some(State::Step20));
+//! assert_eq!(State::Step40.step_next(), None);
+//! assert_eq!(State::Step20.step_prev(), Some(State::Step10));
+//! assert_eq!(State::Step10.step_prev(), None);
+//! assert_eq!(State::Idle.step_next(), None);
+//! assert_eq!(State::STEP_COUNT, 4);
+//! ```
+//!
+//! A raw value can be clamped to a variant of a range:
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range(const_fns)]
+//! #[repr(u8)]
+//! #[derive(Debug, PartialEq)]
+//! enum Volume {
+//!     Mute = 0,
+//!     #[range(format = "Level{value}", start = 10, end = 40, step = 10, skip = [30], range_check = "is_level")]
+//!     Level,
+//! }
+//!
+//! assert_eq!(Volume::clamp_to_level(0), Volume::Level10);
+//! assert_eq!(Volume::clamp_to_level(25), Volume::Level20);
+//! assert_eq!(Volume::clamp_to_level(39), Volume::Level20);
+//! assert_eq!(Volume::clamp_to_level(40), Volume::Level40);
+//! assert_eq!(Volume::clamp_to_level(255), Volume::Level40);
+//! ```
+//!
+//! A range can give the `Default` variant of the enum:
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range]
+//! #[repr(u8)]
+//! #[derive(Debug, PartialEq)]
+//! enum State {
+//!     Off = 0,
+//!     #[range(format = "Step{value}", start = 10, end = 40, step = 10, default)]
+//!     Step,
+//! }
+//!
+//! #[enum_range]
+//! #[derive(Debug, PartialEq)]
+//! enum Speed {
+//!     #[range(format = "Gear{value}", start = 1, end = 5, default_value = 3)]
+//!     Gear,
+//! }
+//!
+//! assert_eq!(State::default(), State::Step10);
+//! assert_eq!(Speed::default(), Speed::Gear3);
+//! ```
+//!
+//! The values of a range can be bit positions:
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range(const_fns)]
+//! #[repr(u8)]
+//! #[derive(Debug, PartialEq)]
+//! enum Flag {
+//!     #[range(format = "Bit{value}", start = 0, end = 7, as_bit)]
+//!     Bit,
+//!     None = 8,
+//! }
+//!
+//! const MASK: u8 = Flag::Bit3.bit_mask();
+//! assert_eq!(MASK, 0b1000);
+//! assert_eq!(Flag::Bit7.bit_mask(), 0x80);
+//! assert_eq!(Flag::None.bit_mask(), 0);
+//! assert_eq!(Flag::from_mask(0b100), Some(Flag::Bit2));
+//! assert_eq!(Flag::from_mask(0b110), None);
+//! ```
+//!
+//! The bit positions must fit in the repr:
+//!
+//! ```compile_fail
+//! use enum_range::enum_range;
+//!
+//! #[enum_range]
+//! #[repr(u8)]
+//! enum Flag {
+//!     #[range(format = "Bit{value}", start = 0, end = 8, as_bit)]
+//!     Bit,
+//! }
+//! ```
+//!
+//! Only one range can be the default:
+//!
+//! ```compile_fail
+//! use enum_range::enum_range;
+//!
+//! #[enum_range]
+//! enum State {
+//!     #[range(format = "Low{value}", start = 0, end = 1, default)]
+//!     Low,
+//!     #[range(format = "High{value}", start = 2, end = 3, default)]
+//!     High,
+//! }
+//! ```
+//!
+//! The annotated variant can carry fields, they are copied on every generated variant:
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range(from_repr)]
+//! #[repr(u8)]
+//! enum Opcode {
+//!     Nop = 0,
+//!     #[range(format = "Load{index}", start = 0x10, end = 0x13, range_check = "is_load")]
+//!     Load(u8),
+//!     #[range(format = "Store{index}", start = 0x20, end = 0x23, range_check = "is_store")]
+//!     Store { raw: u8 },
+//! }
+//!
+//! let load = Opcode::Load2(7);
+//! assert!(load.is_load());
+//! assert_eq!(load.value(), 0x12);
+//! assert!(Opcode::Store0 { raw: 1 }.is_store());
+//! // variants with fields can't be built from their discriminator alone
+//! assert!(Opcode::from_repr(0x12).is_none());
+//! assert!(matches!(Opcode::from_repr(0), Some(Opcode::Nop)));
+//! ```
+//!
+//! Generic enums are supported, the generated items have the same generics as the enum:
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range(display)]
+//! #[repr(u8)]
+//! enum Wrapper<T: Clone> {
+//!     Value(T) = 0,
+//!     #[range(start = 1, end = 3, range_check = "is_empty")]
+//!     Empty,
+//! }
+//!
+//! let empty = Wrapper::<String>::Empty1;
+//! assert!(empty.is_empty());
+//! assert!(!Wrapper::Value(5).is_empty());
+//! assert_eq!(Wrapper::<u8>::empty_iter().count(), 3);
+//! assert_eq!(empty.to_string(), "Empty1");
+//! ```
+//!
+//! When the enum has a numerical repr, `is_in_any_range` checks if a variant was generated by any of the ranges,
+//! including the ranges without a `range_check`:
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range]
+//! #[repr(u8)]
+//! enum Decoded {
+//!     Known = 0,
+//!     #[range(start = 1, end = 3, range_check = "is_low")]
+//!     Low,
+//!     #[range(start = 10, end = 12)]
+//!     High,
+//! }
+//!
+//! assert!(Decoded::Low1.is_in_any_range());
+//! assert!(Decoded::High2.is_in_any_range());
+//! assert!(!Decoded::Known.is_in_any_range());
+//! ```
+//!
+//! `which_range` gives the label of the range which generated a variant:
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range]
+//! #[repr(u8)]
+//! enum Decoded {
+//!     Known = 0,
+//!     #[range(start = 1, end = 3, range_check = "is_low")]
+//!     Low,
+//!     #[range(start = 10, end = 12, label = "high values")]
+//!     High,
+//!     #[range(start = 20, end = 22)]
+//!     Reserved,
+//! }
+//!
+//! assert_eq!(Decoded::Low1.which_range(), Some("low"));
+//! assert_eq!(Decoded::High2.which_range(), Some("high values"));
+//! assert_eq!(Decoded::Reserved0.which_range(), Some("Reserved"));
+//! assert_eq!(Decoded::Known.which_range(), None);
+//! ```
+//!
+//! `matches_category` checks a variant against the label of a range or the name of a category of the enum,
+//! all listed by the `CATEGORY_NAMES` const. Unlike `which_range`, a variant can match several of them:
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range(category(name = "assigned", start = 0, end = 249))]
+//! #[repr(u8)]
+//! enum Registry {
+//!     Known = 0,
+//!     #[range(format = "Reserved{value}", start = 240, end = 255, range_check = "is_reserved")]
+//!     Reserved,
+//!     #[range(start = 250, end = 255, range_check = "is_experimental", checker_only)]
+//!     Experimental,
+//! }
+//!
+//! assert_eq!(Registry::CATEGORY_NAMES, ["reserved", "experimental", "assigned"]);
+//! assert!(Registry::Reserved252.matches_category("reserved"));
+//! assert!(Registry::Reserved252.matches_category("experimental"));
+//! assert!(!Registry::Reserved252.matches_category("assigned"));
+//! assert!(Registry::Reserved245.matches_category("assigned"));
+//! assert!(Registry::Known.matches_category("assigned"));
+//! assert!(!Registry::Known.matches_category("unknown"));
+//! ```
+//!
+//! `index_of` gives the position of a variant in its range, in the order they are generated:
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range]
+//! #[repr(u8)]
+//! enum Stepped {
+//!     Known = 0,
+//!     #[range(format = "Step{value}", start = 10, end = 40, step = 10, skip = [20])]
+//!     Step,
+//! }
+//!
+//! assert_eq!(Stepped::Step10.index_of(), Some(0));
+//! assert_eq!(Stepped::Step30.index_of(), Some(1));
+//! assert_eq!(Stepped::Step40.index_of(), Some(2));
+//! assert_eq!(Stepped::Known.index_of(), None);
+//! ```
+//!
+//! The doc comments of the generated variants can be templated:
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range]
+//! #[repr(u8)]
+//! enum Documented {
+//!     /// Reserved block (replaced by the `doc` template)
+//!     #[range(start = 1, end = 3, doc = "Reserved value {value} (number {index} of the block)")]
+//!     Reserved,
+//! }
+//! ```
+//!
+//! Ranges can't overlap with each other nor with explicit discriminators of other variants:
+//!
+//! ```compile_fail
+//! use enum_range::enum_range;
+//!
+//! #[enum_range]
+//! #[repr(u8)]
+//! enum Overlapping {
+//!     #[range(start = 200, end = 210)]
+//!     First,
+//!     #[range(start = 208, end = 220)]
+//!     Second,
+//! }
+//! ```
+//!
+//! An explicit discriminator colliding with a range declared after it is an error as well:
+//!
+//! ```compile_fail
+//! use enum_range::enum_range;
+//!
+//! #[enum_range]
+//! #[repr(u8)]
+//! enum Colliding {
+//!     A = 205,
+//!     #[range(start = 200, end = 210)]
+//!     B,
+//! }
+//! ```
+//!
+//! ```compile_fail
+//! use enum_range::enum_range;
+//!
+//! #[enum_range]
+//! #[repr(u8)]
+//! enum Between {
+//!     #[range(start = 100, end = 110)]
+//!     Low,
+//!     Middle = 215,
+//!     #[range(start = 210, end = 220)]
+//!     High,
+//! }
+//! ```
+//!
+//! The explicit discriminators can be interleaved with the ranges in any order as long as they don't collide:
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range]
+//! #[repr(u8)]
+//! enum Interleaved {
+//!     #[range(start = 200, end = 210)]
+//!     High,
+//!     Middle = 150,
+//!     #[range(start = 100, end = 110)]
+//!     Low,
+//!     Last = 211,
+//! }
+//!
+//! assert_eq!(Interleaved::High10 as u8, 210);
+//! assert_eq!(Interleaved::Middle as u8, 150);
+//! assert_eq!(Interleaved::Low0 as u8, 100);
+//! assert_eq!(Interleaved::Last as u8, 211);
+//! ```
+//!
+//! The variants without a discriminator take the value following the previous variant,
+//! which can't be a value of a range either:
+//!
+//! ```compile_fail
+//! use enum_range::enum_range;
+//!
+//! #[enum_range]
+//! #[repr(u8)]
+//! enum Implicit {
+//!     Zero,
+//!     One,
+//!     #[range(start = 5, end = 7)]
+//!     Range,
+//!     Eight,
+//!     #[range(start = 8, end = 9)]
+//!     Colliding,
+//! }
+//! ```
+//!
+//! The `range_check` of an enum without a numerical repr matches the variants of the range instead of comparing
+//! their value, which makes for a long match with large ranges. The items using the values of the range
+//! (the `_value` function, the `_MIN` and `_MAX` consts and the bounds function) aren't generated then:
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range]
+//! #[derive(Clone, Copy, Debug, PartialEq)]
+//! enum NoRepr {
+//!     #[range(start = 1, end = 3, range_check = "is_low")]
+//!     Low,
+//!     High,
+//! }
+//!
+//! assert!(NoRepr::Low2.is_low());
+//! assert!(!NoRepr::High.is_low());
+//! assert_eq!(NoRepr::LOW_COUNT, 3);
+//! assert_eq!(NoRepr::Low0.low_next(), Some(NoRepr::Low1));
+//! ```
+//!
+//! Asking for these items without a numerical repr is a compile error:
+//!
+//! ```compile_fail
+//! use enum_range::enum_range;
+//!
+//! #[enum_range]
+//! enum NoRepr {
+//!     #[range(start = 1, end = 3, range_check = "is_low", bounds_fn = "low")]
+//!     Low,
+//! }
+//! ```
+//!
+//! The checks use `core::matches!` by its full path, so a `matches!` macro of the crate doesn't change them:
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! macro_rules! matches {
+//!     ($($tokens:tt)*) => {
+//!         false
+//!     };
+//! }
+//!
+//! #[enum_range]
+//! enum NoRepr {
+//!     #[range(start = 1, end = 3, range_check = "is_low")]
+//!     Low,
+//! }
+//!
+//! #[enum_range(category(name = "small", start = 0, end = 9))]
+//! #[repr(u8)]
+//! enum WithRepr {
+//!     #[range(start = 1, end = 3, range_check = "is_low")]
+//!     Low,
+//! }
+//!
+//! assert!(NoRepr::Low2.is_low());
+//! assert!(WithRepr::Low2.is_low());
+//! assert!(WithRepr::Low2.is_small());
+//! assert!(!matches!(WithRepr::Low2, WithRepr::Low2));
+//! ```
+//!
+//! The attribute can also be spelled by its path in the crate, when another `range` attribute is in scope:
+//!
+//! ```rust
+//! #[enum_range::enum_range]
+//! #[repr(u8)]
+//! enum Qualified {
+//!     Zero = 0,
+//!     #[enum_range::range(format = "Low{index}", start = 1, end = 3, range_check = "is_low")]
+//!     Low,
+//!     #[::enum_range::range(10..=11)]
+//!     High,
+//! }
+//!
+//! assert_eq!(Qualified::Low2 as u8, 3);
+//! assert!(Qualified::Low0.is_low());
+//! assert_eq!(Qualified::High1 as u8, 11);
+//! ```
+//!
+//! An unknown option, such as a misspelled `start`, is an error listing the valid options:
+//!
+//! ```compile_fail
+//! use enum_range::enum_range;
+//!
+//! #[enum_range]
+//! #[repr(u8)]
+//! enum Typo {
+//!     #[range(strat = 10, end = 20)]
+//!     Value,
+//! }
+//! ```
+//!
+//! # Options
+//!
+//! The `#[enum_range(...)]` attribute takes options changing the whole enum.
+//!
+//! The options are parsed by darling, an unknown option is an error:
+//!
+//! ```compile_fail
+//! use enum_range::enum_range;
+//!
+//! #[enum_range(from_rep)]
+//! #[repr(u8)]
+//! enum Typo {
+//!     #[range(start = 1, end = 3)]
+//!     Value,
+//! }
+//! ```
+//!
+//! Parameters:
+//! - `repr` (optional): the numerical repr of the enum, e.g. `repr = "u16"`, used by the options requiring one.
+//!   The macro adds the `#[repr(u16)]` attribute to the enum, which can then leave it out.
+//!   When the enum also has a numerical repr attribute, both have to be the same.
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range(repr = "u16", from_repr)]
+//! #[derive(Debug, PartialEq)]
+//! enum RangedEnum {
+//!     NormalVariant = 1,
+//!     #[range(format = "WellKnown{index}", start = 300, end = 310, range_check = "is_well_known")]
+//!     RangeVariant,
+//! }
+//!
+//! assert_eq!(RangedEnum::WellKnown2 as u16, 302);
+//! assert!(RangedEnum::is_well_known_value(305));
+//! assert_eq!(RangedEnum::from_repr(1), Some(RangedEnum::NormalVariant));
+//! ```
+//!
+//! ```compile_fail
+//! use enum_range::enum_range;
+//!
+//! #[enum_range(repr = "u16")]
+//! #[repr(u8)]
+//! enum Conflicting {
+//!     #[range(start = 1, end = 3)]
+//!     Value,
+//! }
+//! ```
+//!
+//! - `format` (optional): the `format` of the ranges which don't have their own, e.g. `format = "{name}_{value}"`
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range(format = "{name}_{value}")]
+//! #[repr(u8)]
+//! #[allow(non_camel_case_types)]
+//! enum RangedEnum {
+//!     #[range(start = 1, end = 2)]
+//!     Low,
+//!     #[range(start = 10, end = 11)]
+//!     High,
+//!     #[range(format = "Custom{index}", start = 20, end = 21)]
+//!     Custom,
+//! }
+//!
+//! assert_eq!(RangedEnum::Low_2 as u8, 2);
+//! assert_eq!(RangedEnum::High_10 as u8, 10);
+//! assert_eq!(RangedEnum::Custom1 as u8, 21);
+//! ```
+//!
+//! - `auto_checks` (optional): gives the ranges without a `range_check` one named after the annotated variant in
+//!   snake case, e.g. `is_private_use` for `PrivateUse`. The `which_range` label of the range follows it.
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range(auto_checks)]
+//! #[repr(u8)]
+//! enum RangedEnum {
+//!     #[range(start = 1, end = 3)]
+//!     PrivateUse,
+//!     #[range(start = 10, end = 12, range_check = "is_known")]
+//!     WellKnown,
+//! }
+//!
+//! assert!(RangedEnum::PrivateUse1.is_private_use());
+//! assert!(RangedEnum::is_private_use_value(3));
+//! assert!(RangedEnum::WellKnown0.is_known());
+//! assert_eq!(RangedEnum::PRIVATE_USE_COUNT, 3);
+//! assert_eq!(RangedEnum::PrivateUse0.which_range(), Some("private_use"));
+//! ```
+//!
+//! - `from_repr` (optional): generates `pub fn from_repr(value: repr) -> Option<Self>` returning the variant
+//!   with the discriminator `value`, including the variants generated from the ranges.
+//!   This requires the enum to have a numerical repr attribute.
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range(from_repr)]
+//! #[repr(u8)]
+//! #[derive(Debug, PartialEq)]
+//! enum RangedEnum {
+//!     NormalVariant = 1,
+//!     #[range(format = "WellKnown{index}", start = 206, end = 210)]
+//!     RangeVariant,
+//!     OtherNormalVariant,
+//! }
+//!
+//! assert_eq!(RangedEnum::from_repr(1), Some(RangedEnum::NormalVariant));
+//! assert_eq!(RangedEnum::from_repr(208), Some(RangedEnum::WellKnown2));
+//! assert_eq!(RangedEnum::from_repr(211), Some(RangedEnum::OtherNormalVariant));
+//! assert_eq!(RangedEnum::from_repr(0), None);
+//! ```
+//!
+//! - `saturating_from_repr` (optional): generates `pub fn saturating_from_repr(value: repr) -> Self` returning the
+//!   variant with the discriminator `value`, or the one with the nearest lower discriminator when no variant has it.
+//!   The values below the lowest discriminator give the lowest variant, and those above the highest one the highest
+//!   variant. This requires a numerical repr attribute, variants without fields or a cfg, and discriminators known
+//!   by the macro.
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range(saturating_from_repr)]
+//! #[repr(u8)]
+//! #[derive(Debug, PartialEq)]
+//! enum Level {
+//!     Off = 10,
+//!     #[range(format = "Low{index}", start = 20, end = 22)]
+//!     Low,
+//!     High = 40,
+//! }
+//!
+//! assert_eq!(Level::saturating_from_repr(21), Level::Low1);
+//! assert_eq!(Level::saturating_from_repr(0), Level::Off);
+//! assert_eq!(Level::saturating_from_repr(15), Level::Off);
+//! assert_eq!(Level::saturating_from_repr(30), Level::Low2);
+//! assert_eq!(Level::saturating_from_repr(255), Level::High);
+//! ```
+//!
+//! - `try_from` (optional): implements `TryFrom<repr>` for the enum, with the same mapping as `from_repr`.
+//!   The conversion fails with a generated `EnumNameTryFromError` carrying the offending value, which implements
+//!   `std::error::Error`, or `core::error::Error` with the `core_error` feature (Rust 1.81).
+//!   This requires the enum to have a numerical repr attribute.
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range(try_from)]
+//! #[repr(u16)]
+//! #[derive(Debug, PartialEq)]
+//! enum RangedEnum {
+//!     NormalVariant = 1,
+//!     #[range(format = "WellKnown{index}", start = 206, end = 210)]
+//!     RangeVariant,
+//! }
+//!
+//! assert_eq!(RangedEnum::try_from(206), Ok(RangedEnum::WellKnown0));
+//! assert_eq!(RangedEnum::try_from(2), Err(RangedEnumTryFromError(2)));
+//! assert_eq!(RangedEnumTryFromError(2).to_string(), "invalid RangedEnum discriminator: 2");
+//! let error: Box<dyn std::error::Error> = Box::new(RangedEnumTryFromError(2));
+//! assert!(error.source().is_none());
+//! ```
+//!
+//! The generated code names the items of `core` by their full path, so it doesn't mind the names shadowing them:
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[allow(dead_code)]
+//! enum Shadowing {
+//!     Ok,
+//!     Err,
+//!     Some,
+//!     None,
+//! }
+//! use Shadowing::*;
+//!
+//! #[enum_range(from_repr, try_from, from_str)]
+//! #[repr(u8)]
+//! #[derive(Debug, PartialEq)]
+//! enum RangedEnum {
+//!     NormalVariant = 1,
+//!     #[range(start = 2, end = 3)]
+//!     RangeVariant,
+//! }
+//!
+//! assert_eq!(RangedEnum::from_repr(1), Option::Some(RangedEnum::NormalVariant));
+//! assert_eq!(RangedEnum::try_from(2), Result::Ok(RangedEnum::RangeVariant0));
+//! assert_eq!("NormalVariant".parse(), Result::Ok(RangedEnum::NormalVariant));
+//! assert_eq!(RangedEnum::try_from("Unknown").unwrap_err().input, "Unknown");
+//! ```
+//!
+//! - `eq_repr` (optional): implements `PartialEq<repr>` for the enum and `PartialEq<Enum>` for the repr,
+//!   comparing the discriminator of a variant to a raw value. This requires the enum to have a numerical repr attribute.
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range(eq_repr)]
+//! #[repr(u16)]
+//! enum RangedEnum {
+//!     NormalVariant = 1,
+//!     #[range(format = "WellKnown{index}", start = 206, end = 210)]
+//!     RangeVariant,
+//! }
+//!
+//! assert!(RangedEnum::WellKnown0 == 206u16);
+//! assert!(RangedEnum::NormalVariant != 2u16);
+//! assert!(207u16 == RangedEnum::WellKnown1);
+//! ```
+//!
+//! - `into_repr` (optional): implements `From<Enum>` for the repr, converting a variant to its discriminator value,
+//!   so that the enum can be given to the functions taking an `Into<repr>`.
+//!   This requires the enum to have a numerical repr attribute.
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range(into_repr)]
+//! #[repr(u16)]
+//! enum RangedEnum {
+//!     NormalVariant = 1,
+//!     #[range(format = "WellKnown{index}", start = 206, end = 210)]
+//!     RangeVariant,
+//! }
+//!
+//! fn encode(value: impl Into<u16>) -> [u8; 2] {
+//!     value.into().to_be_bytes()
+//! }
+//!
+//! assert_eq!(u16::from(RangedEnum::WellKnown1), 207);
+//! assert_eq!(encode(RangedEnum::NormalVariant), [0, 1]);
+//! ```
+//!
+//! - `from_str` (optional): implements `FromStr` for the enum, parsing the names of the variants without fields
+//!   (including the generated ones). The parsing fails with a generated `EnumNameFromStrError` carrying the offending string.
+//!   It also implements `TryFrom<&str>`, failing with a generated `EnumNameParseError` holding the offending string
+//!   along with the valid names, the first ones being written by its `Display` implementation.
+//!   This doesn't require a numerical repr attribute. The errors hold a `String`, so `no_std` crates
+//!   must be able to link the `alloc` crate to use this option, which is the only one requiring it.
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range(from_str)]
+//! #[derive(Debug, PartialEq)]
+//! enum RangedEnum {
+//!     NormalVariant,
+//!     #[range(format = "WellKnown{index}", start = 206, end = 210)]
+//!     RangeVariant,
+//! }
+//!
+//! assert_eq!("WellKnown0".parse(), Ok(RangedEnum::WellKnown0));
+//! assert_eq!("NormalVariant".parse(), Ok(RangedEnum::NormalVariant));
+//! assert_eq!("RangeVariant".parse::<RangedEnum>(), Err(RangedEnumFromStrError("RangeVariant".to_string())));
+//! assert_eq!(
+//!     RangedEnumFromStrError("Other".to_string()).to_string(),
+//!     "invalid RangedEnum variant name: Other"
+//! );
+//!
+//! assert_eq!(RangedEnum::try_from("WellKnown4"), Ok(RangedEnum::WellKnown4));
+//! let error = RangedEnum::try_from("WellKnown5").unwrap_err();
+//! assert_eq!(error.input, "WellKnown5");
+//! assert_eq!(error.valid_names.len(), 6);
+//! assert_eq!(
+//!     error.to_string(),
+//!     "invalid RangedEnum variant name: WellKnown5, expected one of NormalVariant, WellKnown0, WellKnown1, WellKnown2, WellKnown3, WellKnown4"
+//! );
+//! ```
+//!
+//! The `Display` of the `TryFrom<&str>` error only writes the first names:
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range(from_str)]
+//! #[derive(Debug)]
+//! enum Many {
+//!     #[range(format = "V{index}", start = 0, end = 9)]
+//!     V,
+//! }
+//!
+//! assert_eq!(
+//!     Many::try_from("V10").unwrap_err().to_string(),
+//!     "invalid Many variant name: V10, expected one of V0, V1, V2, V3, V4, V5, V6, V7, and 2 more"
+//! );
+//! ```
+//!
+//! - `as_str` (optional): generates the `as_str` method returning the name of a variant as a `&'static str`.
+//!   It is the reverse of `from_str` and doesn't require a numerical repr attribute either
+//! - `as_str_fn` (optional): the name of this method instead of `as_str`, giving it also generates the method
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range(from_str, as_str, const_fns)]
+//! #[derive(Debug, Clone, Copy, PartialEq)]
+//! enum RangedEnum {
+//!     NormalVariant,
+//!     #[range(format = "WellKnown{index}", start = 206, end = 210)]
+//!     RangeVariant,
+//! }
+//!
+//! const NAME: &str = RangedEnum::WellKnown3.as_str();
+//! assert_eq!(NAME, "WellKnown3");
+//! for variant in [RangedEnum::NormalVariant, RangedEnum::WellKnown0, RangedEnum::WellKnown4] {
+//!     assert_eq!(variant.as_str().parse(), Ok(variant));
+//! }
+//! ```
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range(as_str_fn = "name")]
+//! enum Shape {
+//!     Point,
+//!     Circle(u32),
+//!     #[range(format = "Polygon{value}", start = 3, end = 4)]
+//!     Polygon { side: u32 },
+//! }
+//!
+//! assert_eq!(Shape::Circle(2).name(), "Circle");
+//! assert_eq!(Shape::Polygon4 { side: 1 }.name(), "Polygon4");
+//! ```
+//!
+//! ```compile_fail
+//! use enum_range::enum_range;
+//!
+//! #[enum_range(as_str_fn = "variant name")]
+//! enum Shape {
+//!     Point,
+//! }
+//! ```
+//!
+//! - `value_fn` (optional): the name of the method returning the discriminator value of a variant (default is `value`).
+//!   The method is generated whenever the enum has a numerical repr attribute.
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range(value_fn = "raw")]
+//! #[repr(u16)]
+//! #[derive(Clone, Copy)]
+//! enum RangedEnum {
+//!     NormalVariant = 1,
+//!     #[range(format = "WellKnown{index}", start = 206, end = 210)]
+//!     RangeVariant,
+//! }
+//!
+//! assert_eq!(RangedEnum::NormalVariant.raw(), 1);
+//! assert_eq!(RangedEnum::WellKnown4.raw(), 210);
+//! ```
+//!
+//! ```compile_fail
+//! use enum_range::enum_range;
+//!
+//! #[enum_range(value_fn = "raw value")]
+//! #[repr(u16)]
+//! enum RangedEnum {
+//!     NormalVariant = 1,
+//! }
+//! ```
+//!
+//! - `display` (optional): implements `Display` for the enum, writing the name of the variant.
+//!   The variants of a range with a `display` template are written with it instead.
+//!   Like a `str`, the text is padded to the width, fill and alignment of the formatter.
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range(display)]
+//! #[repr(u16)]
+//! enum RangedEnum {
+//!     NormalVariant = 1,
+//!     #[range(format = "WellKnown{index}", start = 206, end = 210)]
+//!     WellKnown,
+//!     #[range(format = "Private{index}", start = 220, end = 221, display = "private use #{index} ({value:x})")]
+//!     Private,
+//! }
+//!
+//! assert_eq!(RangedEnum::NormalVariant.to_string(), "NormalVariant");
+//! assert_eq!(RangedEnum::WellKnown0.to_string(), "WellKnown0");
+//! assert_eq!(RangedEnum::Private1.to_string(), "private use #1 (dd)");
+//! assert_eq!(format!("{:>12}", RangedEnum::WellKnown0), "  WellKnown0");
+//! assert_eq!(format!("{:-<12}|", RangedEnum::WellKnown1), "WellKnown1--|");
+//! ```
+//!
+//! - `debug_with_value` (optional): implements `Debug` for the enum, writing the name of the variant followed by
+//!   its discriminator value in parentheses, e.g. `WellKnown0(206)`. It replaces `#[derive(Debug)]` and doesn't write
+//!   the fields of the variants. This requires the enum to have a numerical repr attribute.
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range(debug_with_value)]
+//! #[repr(u16)]
+//! enum RangedEnum {
+//!     NormalVariant = 1,
+//!     #[range(format = "WellKnown{index}", start = 206, end = 210)]
+//!     WellKnown,
+//! }
+//!
+//! assert_eq!(format!("{:?}", RangedEnum::NormalVariant), "NormalVariant(1)");
+//! assert_eq!(format!("{:?}", RangedEnum::WellKnown0), "WellKnown0(206)");
+//! assert_eq!(format!("{:?}", Some(RangedEnum::WellKnown4)), "Some(WellKnown4(210))");
+//! ```
+//!
+//! - `value_radix` (optional): the radix of the discriminator values written by `debug_with_value`, one of `dec`
+//!   (default), `hex` or `bin`. The hexadecimal and binary values are prefixed with `0x` and `0b`.
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range(debug_with_value, value_radix = "hex")]
+//! #[repr(u8)]
+//! enum Opcode {
+//!     Nop = 0x0F,
+//!     #[range(format = "WellKnown{index}", start = 0xCE, end = 0xCF)]
+//!     WellKnown,
+//! }
+//!
+//! assert_eq!(format!("{:?}", Opcode::Nop), "Nop(0xF)");
+//! assert_eq!(format!("{:?}", Opcode::WellKnown0), "WellKnown0(0xCE)");
+//!
+//! #[enum_range(debug_with_value, value_radix = "bin")]
+//! #[repr(u8)]
+//! enum Flags {
+//!     Read = 0b100,
+//! }
+//!
+//! assert_eq!(format!("{:?}", Flags::Read), "Read(0b100)");
+//! ```
+//!
+//! - `const_fns` (optional): generates the `range_check` methods, the `value_fn` accessor and `from_repr` as `const fn`
+//!   so that they can be used in const contexts. This is opt-in since it requires a toolchain supporting enum casts
+//!   in `const fn`.
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range(const_fns, from_repr)]
+//! #[repr(u8)]
+//! #[derive(Clone, Copy)]
+//! enum RangedEnum {
+//!     NormalVariant = 1,
+//!     #[range(format = "WellKnown{index}", start = 206, end = 210, range_check = "is_well_known", by_value)]
+//!     RangeVariant,
+//! }
+//!
+//! const WELL_KNOWN: bool = RangedEnum::WellKnown2.is_well_known();
+//! const NORMAL: bool = RangedEnum::NormalVariant.is_well_known();
+//! const VALUE: u8 = RangedEnum::WellKnown2.value();
+//! const FIRST: Option<RangedEnum> = RangedEnum::from_repr(206);
+//! assert!(WELL_KNOWN);
+//! assert!(!NORMAL);
+//! assert_eq!(VALUE, 208);
+//! assert!(matches!(FIRST, Some(RangedEnum::WellKnown0)));
+//! ```
+//!
+//! - `vis` (optional): the visibility of the generated methods and consts (default is `pub`), e.g. `vis = "pub(crate)"`.
+//!   The `vis` of a `#[range(...)]` attribute overrides it for the items of that range.
+//!
+//! ```rust
+//! mod internal {
+//!     use enum_range::enum_range;
+//!
+//!     #[enum_range(vis = "pub(crate)", from_repr)]
+//!     #[repr(u8)]
+//!     pub enum RangedEnum {
+//!         NormalVariant = 1,
+//!         #[range(format = "WellKnown{index}", start = 206, end = 210, range_check = "is_well_known")]
+//!         RangeVariant,
+//!         #[range(format = "Private{index}", start = 220, end = 221, range_check = "is_private", vis = "")]
+//!         Private,
+//!     }
+//!
+//!     pub fn is_private(value: u8) -> bool {
+//!         RangedEnum::from_repr(value).is_some_and(|variant| variant.is_private())
+//!     }
+//! }
+//!
+//! use internal::RangedEnum;
+//!
+//! assert!(RangedEnum::WellKnown0.is_well_known());
+//! assert_eq!(RangedEnum::from_repr(1).map(RangedEnum::value), Some(1));
+//! assert!(internal::is_private(221));
+//! ```
+//!
+//! The `vis` of a range makes its items private to the module of the enum above:
+//!
+//! ```compile_fail
+//! mod internal {
+//!     use enum_range::enum_range;
+//!
+//!     #[enum_range]
+//!     #[repr(u8)]
+//!     pub enum RangedEnum {
+//!         #[range(format = "Private{index}", start = 220, end = 221, range_check = "is_private", vis = "")]
+//!         Private,
+//!     }
+//! }
+//!
+//! assert!(internal::RangedEnum::Private0.is_private());
+//! ```
+//!
+//! - `doc_values` (optional): adds a `value = ...` line giving the discriminator value to the doc comment of
+//!   every generated variant, after their other doc comments (including the `doc` template of the range).
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range(doc_values)]
+//! #[repr(u16)]
+//! enum RangedEnum {
+//!     NormalVariant = 1,
+//!     /// Well known value (documented as `value = 206` to `value = 210`)
+//!     #[range(format = "WellKnown{index}", start = 206, end = 210)]
+//!     RangeVariant,
+//! }
+//! ```
+//!
+//! - `serde` (optional, requires the `serde` feature of this crate): implements `serde::Serialize` and
+//!   `serde::Deserialize` for the enum using its discriminator value, deserializing a value which is not
+//!   the discriminator of a variant is an error. The generated code uses `::serde` so the crate using the
+//!   macro must depend on `serde`. This requires the enum to have a numerical repr attribute.
+//!
+#![cfg_attr(feature = "serde", doc = "```rust")]
+#![cfg_attr(not(feature = "serde"), doc = "```ignore")]
+//! use enum_range::enum_range;
+//!
+//! #[enum_range(serde)]
+//! #[repr(u16)]
+//! #[derive(Debug, PartialEq)]
+//! enum RangedEnum {
+//!     NormalVariant = 1,
+//!     #[range(format = "WellKnown{index}", start = 206, end = 210)]
+//!     RangeVariant,
+//! }
+//!
+//! assert_eq!(serde_json::to_string(&RangedEnum::WellKnown1).unwrap(), "207");
+//! assert_eq!(serde_json::from_str::<RangedEnum>("1").unwrap(), RangedEnum::NormalVariant);
+//! assert!(serde_json::from_str::<RangedEnum>("2").is_err());
+//! ```
+//!
+//! - `names_table` (optional): generates the `VARIANT_NAMES` const, a slice of the names of all the variants
+//!   along with their discriminator value, in the order of their definition.
+//!   This requires the enum to have a numerical repr attribute.
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range(names_table)]
+//! #[repr(u8)]
+//! enum RangedEnum {
+//!     NormalVariant = 1,
+//!     #[range(format = "WellKnown{index}", start = 206, end = 207)]
+//!     RangeVariant,
+//! }
+//!
+//! assert_eq!(
+//!     RangedEnum::VARIANT_NAMES,
+//!     [("NormalVariant", 1), ("WellKnown0", 206), ("WellKnown1", 207)]
+//! );
+//! let value = RangedEnum::VARIANT_NAMES.iter().find(|(name, _)| *name == "WellKnown1").map(|(_, value)| *value);
+//! assert_eq!(value, Some(207));
+//! ```
+//!
+//! - `which_range_fn`, `index_of_fn` and `is_in_any_range_fn` (optional): the names of the methods telling which
+//!   range generated a variant (default is `which_range`, `index_of` and `is_in_any_range`), e.g. when the enum
+//!   already has methods with these names.
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range(which_range_fn = "range_label", index_of_fn = "range_index", is_in_any_range_fn = "is_ranged")]
+//! #[repr(u8)]
+//! enum Decoded {
+//!     Known = 0,
+//!     #[range(start = 1, end = 3, range_check = "is_low")]
+//!     Low,
+//! }
+//!
+//! impl Decoded {
+//!     fn which_range(&self) -> &'static str {
+//!         "mine"
+//!     }
+//! }
+//!
+//! assert_eq!(Decoded::Low2.range_label(), Some("low"));
+//! assert_eq!(Decoded::Low2.range_index(), Some(2));
+//! assert!(Decoded::Low2.is_ranged());
+//! assert!(!Decoded::Known.is_ranged());
+//! assert_eq!(Decoded::Known.which_range(), "mine");
+//! ```
+//!
+//! ```compile_fail
+//! use enum_range::enum_range;
+//!
+//! #[enum_range(which_range_fn = "which range")]
+//! #[repr(u8)]
+//! enum Decoded {
+//!     #[range(start = 1, end = 3)]
+//!     Low,
+//! }
+//! ```
+//!
+//! - `range_enum` (optional): generates the `EnumNameRange` enum with a variant for each range generating variants,
+//!   named after the variant annotated with the range, and the `range` method returning the range of a variant.
+//!   Unlike `which_range`, a match on the range of a variant is checked to handle every range.
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range(range_enum)]
+//! #[repr(u8)]
+//! enum Registry {
+//!     Known = 0,
+//!     #[range(start = 1, end = 3, label = "well known")]
+//!     WellKnown,
+//!     #[range(start = 10, end = 12)]
+//!     PrivateUse,
+//! }
+//!
+//! assert_eq!(Registry::WellKnown1.range(), Some(RegistryRange::WellKnown));
+//! assert_eq!(Registry::PrivateUse0.range(), Some(RegistryRange::PrivateUse));
+//! assert_eq!(Registry::Known.range(), None);
+//!
+//! let describe = |range: RegistryRange| match range {
+//!     RegistryRange::WellKnown => "assigned by the registry",
+//!     RegistryRange::PrivateUse => "free to use",
+//! };
+//! assert_eq!(Registry::PrivateUse2.range().map(describe), Some("free to use"));
+//! ```
+//!
+//! - `range_enum_name`, `names_table_name`, `try_from_error_name`, `from_str_error_name` and `parse_error_name`
+//!   (optional): override the names of the items generated by `range_enum`, `names_table`, `try_from` and `from_str`,
+//!   which are otherwise named after the enum, e.g. to avoid a collision with another item of the module.
+//!   Each of them implies the option generating the item. The module of `wrap_module` is already named by it.
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! struct RegistryRange;
+//!
+//! #[enum_range(
+//!     range_enum_name = "RegistryBlock",
+//!     names_table_name = "NAMES",
+//!     try_from_error_name = "InvalidRegistry",
+//!     parse_error_name = "UnknownRegistry"
+//! )]
+//! #[repr(u8)]
+//! #[derive(Debug, PartialEq)]
+//! enum Registry {
+//!     Known = 0,
+//!     #[range(start = 1, end = 2)]
+//!     WellKnown,
+//! }
+//!
+//! assert_eq!(Registry::WellKnown1.range(), Some(RegistryBlock::WellKnown));
+//! assert_eq!(Registry::NAMES[0], ("Known", 0));
+//! assert_eq!(Registry::try_from(3), Err(InvalidRegistry(3)));
+//! assert_eq!("Known".parse::<Registry>(), Ok(Registry::Known));
+//! let error: UnknownRegistry = Registry::try_from("Unknown").unwrap_err();
+//! assert_eq!(error.input, "Unknown");
+//! ```
+//!
+//! ```compile_fail
+//! use enum_range::enum_range;
+//!
+//! #[enum_range(range_enum_name = "Registry Range")]
+//! #[repr(u8)]
+//! enum Registry {
+//!     #[range(start = 1, end = 2)]
+//!     WellKnown,
+//! }
+//! ```
+//!
+//! - `discriminant_table` (optional): generates the `DISCRIMINANT_TABLE` const, an array of `Option<Self>` indexed by
+//!   the discriminator value minus the lowest one, `DISCRIMINANT_TABLE_MIN`. It decodes a value with a single lookup
+//!   when the discriminators are dense. The array spans from the lowest to the highest discriminator, so the span
+//!   is limited by `max_variants`. This requires a numerical repr attribute, variants without fields or a cfg, and
+//!   discriminators known by the macro.
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range(discriminant_table)]
+//! #[repr(u8)]
+//! #[derive(Debug, Clone, Copy, PartialEq)]
+//! enum Opcode {
+//!     Nop = 10,
+//!     #[range(format = "Load{index}", start = 12, end = 14)]
+//!     Load,
+//! }
+//!
+//! fn decode(value: u8) -> Option<Opcode> {
+//!     let index = value.checked_sub(Opcode::DISCRIMINANT_TABLE_MIN)? as usize;
+//!     Opcode::DISCRIMINANT_TABLE.get(index).copied().flatten()
+//! }
+//!
+//! assert_eq!(Opcode::DISCRIMINANT_TABLE.len(), 5);
+//! assert_eq!(decode(13), Some(Opcode::Load1));
+//! assert_eq!(decode(11), None);
+//! assert_eq!(decode(200), None);
+//! ```
+//!
+//! ```compile_fail
+//! use enum_range::enum_range;
+//!
+//! #[enum_range(discriminant_table)]
+//! #[repr(u32)]
+//! enum Sparse {
+//!     Low = 0,
+//!     High = 100_000,
+//! }
+//! ```
+//!
+//! ```compile_fail
+//! use enum_range::enum_range;
+//!
+//! #[enum_range(discriminant_table)]
+//! #[repr(i128)]
+//! enum Extremes {
+//!     Min = -170141183460469231731687303715884105727,
+//!     Max = 170141183460469231731687303715884105727,
+//! }
+//! ```
+//!
+//! - `category` (optional, can be repeated): generates the `is_{name}` method and the `is_{name}_value` associated
+//!   function checking if a variant or a value is between `start` and `end` (both included), e.g.
+//!   `category(name = "assigned", start = 100, end = 300)`. Unlike a range, a category doesn't generate variants,
+//!   so it can span several ranges and normal variants. The bounds are given as for a range.
+//!   This requires the enum to have a numerical repr attribute.
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range(category(name = "assigned", start = 100, end = 300), category(name = "low", start = 0, end = 99))]
+//! #[repr(u16)]
+//! enum Registry {
+//!     Reserved = 0,
+//!     #[range(format = "WellKnown{index}", start = 100, end = 110, range_check = "is_well_known")]
+//!     WellKnown,
+//!     Custom = 250,
+//!     #[range(format = "Private{index}", start = 400, end = 401)]
+//!     Private,
+//! }
+//!
+//! assert!(Registry::WellKnown3.is_assigned());
+//! assert!(Registry::Custom.is_assigned());
+//! assert!(!Registry::Custom.is_well_known());
+//! assert!(!Registry::Private0.is_assigned());
+//! assert!(Registry::Reserved.is_low());
+//! assert!(Registry::is_assigned_value(300));
+//! assert!(!Registry::is_assigned_value(301));
+//! ```
+//!
+//! The categories don't need the enum to have ranges:
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range(category(name = "low", start = 0, end = 9))]
+//! #[repr(u8)]
+//! enum Level {
+//!     Off = 0,
+//!     High = 10,
+//! }
+//!
+//! assert!(Level::Off.is_low());
+//! assert!(!Level::High.is_low());
+//! ```
+//!
+//! The name of a category must give a valid `is_{name}` method name:
+//!
+//! ```compile_fail
+//! use enum_range::enum_range;
+//!
+//! #[enum_range(category(name = "well known", start = 0, end = 9))]
+//! #[repr(u8)]
+//! enum Level {
+//!     Off = 0,
+//! }
+//! ```
+//!
+//! Like the ones of a range, the bounds relative to a constant are asserted to fit in the repr:
+//!
+//! ```compile_fail
+//! use enum_range::enum_range;
+//!
+//! const BASE: u8 = 250;
+//!
+//! #[enum_range(category(name = "high", start = BASE, end = BASE + 10))]
+//! #[repr(u8)]
+//! enum Level {
+//!     Off = 0,
+//! }
+//! ```
+//!
+//! - `matches_category_fn` and `category_names_const` (optional): the names of the method checking a variant against
+//!   the labels of the ranges and the names of the categories, and of the const listing them (default is
+//!   `matches_category` and `CATEGORY_NAMES`), e.g. when the enum already has items with these names.
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range(
+//!     category(name = "assigned", start = 0, end = 249),
+//!     matches_category_fn = "is_of",
+//!     category_names_const = "GROUPS"
+//! )]
+//! #[repr(u8)]
+//! enum Registry {
+//!     Known = 0,
+//!     #[range(format = "Reserved{value}", start = 250, end = 255, label = "reserved")]
+//!     Reserved,
+//! }
+//!
+//! impl Registry {
+//!     const CATEGORY_NAMES: usize = 2;
+//! }
+//!
+//! assert_eq!(Registry::GROUPS, ["reserved", "assigned"]);
+//! assert!(Registry::Known.is_of("assigned"));
+//! assert!(Registry::Reserved252.is_of("reserved"));
+//! assert_eq!(Registry::CATEGORY_NAMES, 2);
+//! ```
+//!
+//! ```compile_fail
+//! use enum_range::enum_range;
+//!
+//! #[enum_range(category(name = "assigned", start = 0, end = 249), category_names_const = "CATEGORY-NAMES")]
+//! #[repr(u8)]
+//! enum Registry {
+//!     Known = 0,
+//! }
+//! ```
+//!
+//! - `max_variants` (optional): the number of variants a range can generate (default is 4096), counting its skipped values.
+//!   It protects the compiler from the ranges whose bounds have a typo, e.g. `end = 1_000_000` instead of `end = 1_000`.
+//!
+//! ```compile_fail
+//! use enum_range::enum_range;
+//!
+//! #[enum_range]
+//! #[repr(u32)]
+//! enum Huge {
+//!     #[range(start = 0, end = 1_000_000)]
+//!     Value,
+//! }
+//! ```
+//!
+//! ```rust
+//! use enum_range::enum_range;
+//!
+//! #[enum_range(max_variants = 4)]
+//! #[repr(u8)]
+//! enum Small {
+//!     #[range(start = 0, end = 30, step = 10)]
+//!     Value,
+//! }
+//!
+//! assert_eq!(Small::Value3 as u8, 30);
+//! ```
+//!
+//! - `wrap_module` (optional): generates a module with this name holding a const alias of every variant without fields,
+//!   e.g. `wrap_module = "variants"`, so that the variants can be glob-imported without the other items of the enum.
+//!   The module has the visibility of the enum, which can't have generics.
+//!
+//! ```rust
+//! mod registry {
+//!     use enum_range::enum_range;
+//!
+//!     #[enum_range(wrap_module = "variants")]
+//!     #[repr(u8)]
+//!     #[derive(Debug, PartialEq)]
+//!     pub enum Port {
+//!         Reserved = 0,
+//!         #[range(format = "WellKnown{index}", start = 1, end = 3)]
+//!         WellKnown,
+//!     }
+//! }
+//!
+//! use registry::variants::*;
+//!
+//! assert_eq!(WellKnown2, registry::Port::WellKnown2);
+//! assert_eq!(Reserved as u8, 0);
+//! ```
+
 extern crate proc_macro;
+use darling::ast::NestedMeta;
 use darling::{FromMeta, FromVariant};
 use proc_macro::TokenStream;
 use proc_macro2::{Ident, Literal, Span};
 use quote::{format_ident, quote, ToTokens};
 use regex::Regex;
-use std::collections::VecDeque;
-use std::str::FromStr;
+use std::collections::{HashMap, HashSet, VecDeque};
+use syn::ext::IdentExt;
 use syn::punctuated::Punctuated;
-use syn::{parse_macro_input, Data, DataEnum, DeriveInput, Fields, Token, Variant};
+use syn::{
+    parse_macro_input, Attribute, BinOp, Data, DataEnum, DeriveInput, Expr, ExprBinary, ExprGroup,
+    ExprLit, ExprParen, ExprRange, ExprUnary, Fields, Generics, Lit, LitStr, Meta, RangeLimits,
+    Token, UnOp, Variant, Visibility,
+};
 
-/// Represents the instructions defining a range of values in an enum
-///
-/// ```rust
-/// use enum_range::enum_range;
-///
-/// #[enum_range]
-/// #[repr(u8)]
-/// enum RangedEnum {
-///     NormalVariant = 1,
-///     #[range(
-///         format = "WellKnown{index}",
-///         start = 206,
-///         end = 210,
-///         range_check = "is_well_known"
-///     )]
-///     RangeVariant,
-///     OtherNormalVariant = 211,
-/// }
-/// ```
-///
-/// Parameters:
-/// - `start`: the first variant discriminator value in the range (start is included)
-/// - `end`: the last variant discriminator value in the range (end is included)
-/// - `format` (optional): the format used for naming the different variants.
-/// `{index}` is replaced by the index of the variant in the defined range (here 0-5)
-/// `{value}` is replaced by the value of the variant in the defined range (here 206-210)
-/// The default value is `"VariantName{index}"`
-/// - `range_check` (optional): the name of the method used to check if an enum variant is in the defined range (here `RangedEnum::is_well_known`).
-/// This only works if the enum has a numerical repr attribute. If either `range_check` or `repr` are not specified the method is not generated.
-///
+/// Represents the instructions defining a range of values in an enum, see the crate documentation for its parameters
 #[derive(Debug, Default, FromVariant)]
 #[darling(default, attributes(range))]
 struct Range {
     format: Option<String>,
-    start: usize,
-    end: usize,
+    prefix: Option<String>,
+    suffix: Option<String>,
+    #[darling(with = parse_signed)]
+    index_offset: Option<isize>,
+    value_base: Option<usize>,
+    value_scale: Option<usize>,
+    names: Option<Vec<LitStr>>,
+    case: Option<Case>,
+    start: Option<Bound>,
+    end: Option<Bound>,
+    end_exclusive: bool,
+    #[darling(with = parse_windows)]
+    ranges: Option<Vec<(Bound, Bound)>>,
+    count: Option<usize>,
+    step: Option<usize>,
+    #[darling(with = parse_bound_list)]
+    skip: Option<Vec<Bound>>,
+    descending: bool,
     range_check: Option<String>,
+    by_value: bool,
+    range_trait: Option<syn::Path>,
+    value_check: Option<bool>,
+    range_check_bounds: Option<RangeCheckBounds>,
+    bounds_fn: Option<String>,
+    const_prefix: Option<String>,
+    iter_fn: Option<String>,
+    vis: Option<Visibility>,
+    doc: Option<String>,
+    display: Option<String>,
+    label: Option<String>,
+    as_bit: bool,
+    variants: Option<bool>,
+    checker_only: bool,
+    default: bool,
+    default_value: Option<Bound>,
 }
 
-/// Main derive attribute macro. `#[enum_range]` must be applied before any other derives because it changes the definition of the enum
-/// Related attributes are: [Range]
-#[proc_macro_attribute]
-pub fn enum_range(args: TokenStream, input: TokenStream) -> TokenStream {
-    let mut ast = parse_macro_input!(input as DeriveInput);
+/// The options of `#[range(...)]`, the unknown ones are reported along with this list
+const RANGE_OPTIONS: &[&str] = &[
+    "format",
+    "prefix",
+    "suffix",
+    "index_offset",
+    "value_base",
+    "value_scale",
+    "names",
+    "case",
+    "start",
+    "end",
+    "end_exclusive",
+    "ranges",
+    "count",
+    "step",
+    "skip",
+    "descending",
+    "range_check",
+    "by_value",
+    "trait",
+    "range_trait",
+    "value_check",
+    "range_check_bounds",
+    "bounds_fn",
+    "const_prefix",
+    "iter_fn",
+    "vis",
+    "doc",
+    "display",
+    "label",
+    "as_bit",
+    "variants",
+    "checker_only",
+    "default",
+    "default_value",
+];
 
-    let repr = get_repr(&ast);
+impl Range {
+    /// Parses the `#[range(...)]` attribute of a variant, rejecting the unknown options
+    fn parse(variant: &Variant, attr: &Attribute) -> syn::Result<Self> {
+        // The arguments which can't be parsed are left to darling to report
+        if let Ok(args) = attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated) {
+            if let Some(path) = args
+                .iter()
+                .map(Meta::path)
+                .find(|path| !RANGE_OPTIONS.iter().any(|option| path.is_ident(option)))
+            {
+                return Err(syn::Error::new_spanned(
+                    path,
+                    format!(
+                        "unknown range option `{}`, the valid options are: {}",
+                        path.to_token_stream(),
+                        RANGE_OPTIONS.join(", ")
+                    ),
+                ));
+            }
+        }
 
-    let generated = match ast.data {
-        Data::Enum(ref mut data_enum) => generate_enum_ranges(data_enum, &ast.ident, repr),
-        _ => panic!("enum_range can only be applied to enum types"),
-    };
+        Range::from_variant(variant).map_err(|err| {
+            err.into_iter()
+                .map(|err| syn::Error::new(err.span(), err))
+                .reduce(|mut errors, err| {
+                    errors.combine(err);
+                    errors
+                })
+                .expect("darling errors are never empty")
+        })
+    }
 
-    let result = quote! {
-        #ast
+    /// Checks that the range has an end, a count or a list of ranges, before its values are listed
+    fn validate_extent(&self, attr: &Attribute) -> syn::Result<()> {
+        if let Some(windows) = &self.ranges {
+            if self.start.is_some()
+                || self.end.is_some()
+                || self.count.is_some()
+                || self.end_exclusive
+            {
+                return Err(syn::Error::new_spanned(
+                    attr,
+                    "range ranges can't be combined with start, end, count or end_exclusive",
+                ));
+            }
+            if windows.is_empty() {
+                return Err(syn::Error::new_spanned(
+                    attr,
+                    "range ranges requires at least one range",
+                ));
+            }
+        }
 
-        #generated
-    };
+        if self.ranges.is_none() {
+            match (&self.end, self.count) {
+                (Some(_), Some(_)) => {
+                    return Err(syn::Error::new_spanned(
+                        attr,
+                        "range end and count can't be both specified",
+                    ))
+                }
+                (None, None) => {
+                    return Err(syn::Error::new_spanned(
+                        attr,
+                        "range requires either an end or a count",
+                    ))
+                }
+                (None, Some(0)) => {
+                    return Err(syn::Error::new_spanned(
+                        attr,
+                        "range count must be greater than 0",
+                    ))
+                }
+                (None, Some(_)) if self.end_exclusive => {
+                    return Err(syn::Error::new_spanned(
+                        attr,
+                        "range end_exclusive requires an end, not a count",
+                    ))
+                }
+                _ => {}
+            }
+        }
 
-    result.into()
-}
+        Ok(())
+    }
 
-/// Gets the first numerical representation associated to the enum
-fn get_repr(ast: &DeriveInput) -> Option<Ident> {
-    for attr in ast.attrs.iter() {
-        if !attr.path().is_ident("repr") {
-            continue;
+    /// Checks that the range can generate variants fitting in the enum repr
+    fn validate(&self, attr: &Attribute, repr: &Option<Ident>) -> syn::Result<()> {
+        let start = self.start();
+        let first = self.first_bound();
+        let ends = self.end.iter().chain(
+            self.ranges
+                .iter()
+                .flatten()
+                .flat_map(|(start, end)| [start, end]),
+        );
+        if ends
+            .clone()
+            .chain(self.skip.iter().flatten())
+            .any(|bound| !same_base(&bound.base, &first.base))
+        {
+            return Err(syn::Error::new_spanned(
+                attr,
+                "range start, end and skipped values must all be relative to the same constant, or all be literals",
+            ));
+        }
+
+        if ends.into_iter().any(|bound| bound.is_char != first.is_char) {
+            return Err(syn::Error::new_spanned(
+                attr,
+                "range start and end must be both characters or both integers",
+            ));
+        }
+
+        if self.ranges.is_none() {
+            match &self.end {
+                Some(end) if self.end_exclusive && end.value == start.value => {
+                    return Err(syn::Error::new_spanned(
+                        attr,
+                        format!(
+                            "range {}..{} is empty because its end is excluded",
+                            end.value, end.value
+                        ),
+                    ))
+                }
+                _ => {}
+            }
+        }
+
+        for (start, end) in self.windows() {
+            if self.descending && start < end {
+                return Err(syn::Error::new_spanned(
+                    attr,
+                    format!("descending range start ({start}) must be >= end ({end})"),
+                ));
+            }
+
+            if !self.descending && start > end {
+                return Err(syn::Error::new_spanned(
+                    attr,
+                    format!("range start ({start}) must be <= end ({end})"),
+                ));
+            }
+        }
+
+        if self.step == Some(0) {
+            return Err(syn::Error::new_spanned(
+                attr,
+                "range step must be greater than 0",
+            ));
+        }
+
+        // the value of a constant is only known by the compiler, which checks that it fits
+        if let Some((repr, (repr_min, repr_max))) = repr
+            .as_ref()
+            .filter(|_| self.base().is_none())
+            .and_then(|repr| Some((repr, repr_bounds(repr)?)))
+        {
+            for (start, end) in self.windows() {
+                for (bound, value) in [("start", start), ("end", end)] {
+                    if value < repr_min || value > repr_max {
+                        return Err(syn::Error::new_spanned(
+                            attr,
+                            format!("range {bound} ({value}) does not fit in the enum repr `{repr}` ({repr_min}..={repr_max})"),
+                        ));
+                    }
+                }
+            }
+        }
+
+        if self.checker_only && self.variants == Some(true) {
+            return Err(syn::Error::new_spanned(
+                attr,
+                "range checker_only can't generate variants, remove variants = true",
+            ));
+        }
+
+        if !self.generates_variants() {
+            if self.range_check.is_none() || repr.is_none() {
+                return Err(syn::Error::new_spanned(
+                    attr,
+                    "range variants = false and checker_only require a range_check and the enum to have a numerical #[repr(...)] attribute",
+                ));
+            }
+            if self.names.is_some() || self.as_bit || self.default || self.default_value.is_some() {
+                return Err(syn::Error::new_spanned(
+                    attr,
+                    "range variants = false and checker_only can't be combined with names, as_bit, default or default_value",
+                ));
+            }
         }
 
-        let meta_list = attr.meta.require_list().unwrap();
+        if let Some(names) = &self.names {
+            let values_count = self.values().len();
+            if names.len() > values_count {
+                return Err(syn::Error::new_spanned(
+                    &names[values_count],
+                    format!(
+                        "range has {} names but only {values_count} values",
+                        names.len()
+                    ),
+                ));
+            }
+        }
+
+        if let Some(default_value) = &self.default_value {
+            if !same_base(&default_value.base, &first.base)
+                || !self.values().contains(&default_value.value)
+            {
+                return Err(syn::Error::new_spanned(
+                    attr,
+                    "range default_value must be one of the values of the range",
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Gets the last discriminator value of the range, computed from `count` if `end` is not given
+    /// and excluding `end` if `end_exclusive` is set
+    fn end(&self) -> i128 {
+        let start = self.start().value;
+        match (&self.end, self.count) {
+            (Some(end), _) if self.end_exclusive && self.descending => end.value + 1,
+            (Some(end), _) if self.end_exclusive => end.value - 1,
+            (Some(end), _) => end.value,
+            (None, Some(count)) if self.descending => start - (count as i128 - 1),
+            (None, Some(count)) => start + (count as i128 - 1),
+            (None, None) => unreachable!("range is validated before use"),
+        }
+    }
+
+    /// Gets the discriminator values of the variants in the range, in the order they are generated
+    /// Skipped values are not part of it
+    fn values(&self) -> Vec<i128> {
+        // a step of 0 is reported by `validate`, which may come after the values are listed
+        let step = self.step.unwrap_or(1).max(1);
+        let values: Vec<i128> = self
+            .windows()
+            .into_iter()
+            .flat_map(|(start, end)| -> Vec<i128> {
+                if self.descending {
+                    (end..=start).rev().step_by(step).collect()
+                } else {
+                    (start..=end).step_by(step).collect()
+                }
+            })
+            .collect();
+
+        let skipped = self.skipped_values();
+        values
+            .into_iter()
+            .filter(|value| !skipped.contains(value))
+            .collect()
+    }
+
+    /// Checks if the annotated variant is replaced by the variants of the range
+    fn generates_variants(&self) -> bool {
+        !self.checker_only && self.variants.unwrap_or(true)
+    }
 
-        let reprs = meta_list
-            .parse_args_with(Punctuated::<Ident, Token![,]>::parse_terminated)
-            .unwrap();
+    /// Checks if the annotated variant is kept in the enum, when the range only generates its checks
+    fn keeps_variant(&self) -> bool {
+        !self.checker_only && !self.generates_variants()
+    }
+
+    /// Whether the `{value}` placeholder is computed from the index with `value_base` and `value_scale`
+    fn is_value_scaled(&self) -> bool {
+        self.value_base.is_some() || self.value_scale.is_some()
+    }
+
+    /// Gets the `{value}` placeholder of the variant at `index`, `value_base + index * value_scale`
+    fn scaled_value(&self, index: usize) -> Option<i128> {
+        let base = self.value_base.unwrap_or(0) as i128;
+        let scale = self.value_scale.unwrap_or(1) as i128;
+        (index as i128).checked_mul(scale)?.checked_add(base)
+    }
 
-        let regex = Regex::new(r"[uif]\d+").unwrap();
+    /// Gets the number of values of the range, counting the skipped ones, without listing them
+    fn values_count(&self) -> u128 {
+        let step = self.step.unwrap_or(1).max(1) as u128;
+        self.windows()
+            .into_iter()
+            .map(|(start, end)| {
+                let (low, high) = if self.descending {
+                    (end, start)
+                } else {
+                    (start, end)
+                };
+                if low <= high {
+                    high.abs_diff(low) / step + 1
+                } else {
+                    0
+                }
+            })
+            .fold(0, u128::saturating_add)
+    }
 
-        return reprs
+    /// Gets the values between the bounds of the range which are skipped
+    fn skipped_values(&self) -> Vec<i128> {
+        let windows = self.windows();
+        self.skip
             .iter()
-            .find(|repr| regex.is_match(&repr.to_string()))
-            .cloned();
+            .flatten()
+            .map(|bound| bound.value)
+            .filter(|value| {
+                windows
+                    .iter()
+                    .any(|(start, end)| (*start.min(end)..=*start.max(end)).contains(value))
+            })
+            .collect()
     }
 
-    return None;
-}
+    /// Gets the `start` of the range, 0 if it isn't given
+    fn start(&self) -> Bound {
+        self.start.clone().unwrap_or_default()
+    }
 
-/// Generates the variants for each range defined on the enum
-/// Changes the structure in place
-fn generate_enum_ranges(
-    data_enum: &mut DataEnum,
-    enum_ident: &Ident,
-    repr: Option<Ident>,
-) -> proc_macro2::TokenStream {
-    let mut ranges = VecDeque::new();
+    /// Gets the bound starting the range, which is the start of the first of its `ranges` if it has any
+    fn first_bound(&self) -> Bound {
+        match self.ranges.as_ref().and_then(|windows| windows.first()) {
+            Some((start, _)) => start.clone(),
+            None => self.start(),
+        }
+    }
 
-    // Find all ranges defined in the enum
-    for (variant_index, variant) in data_enum.variants.iter_mut().enumerate() {
-        if let Ok(range) = Range::from_variant(variant) {
-            // extract "range" attribute
-            let index = variant
-                .attrs
+    /// Gets the first and the last discriminator values of each interval of the range, in the order they are generated
+    fn windows(&self) -> Vec<(i128, i128)> {
+        match &self.ranges {
+            Some(windows) => windows
                 .iter()
-                .position(|attr| attr.path().is_ident("range"));
+                .map(|(start, end)| (start.value, end.value))
+                .collect(),
+            None => vec![(self.start().value, self.end())],
+        }
+    }
 
-            if let None = index {
-                continue;
+    /// Gets the name of the range given by `which_range`: its `label`, otherwise the name of its `range_check`
+    /// without the `is_` prefix, otherwise the name of the annotated variant
+    fn label(&self, variant: &Variant) -> String {
+        match (&self.label, &self.range_check) {
+            (Some(label), _) => label.clone(),
+            (None, Some(range_check)) => range_check_stem(range_check).to_string(),
+            (None, None) => variant.ident.unraw().to_string(),
+        }
+    }
+
+    /// Gets the constant the bounds of the range are relative to, its values are then offsets from it
+    fn base(&self) -> Option<Expr> {
+        self.first_bound().base
+    }
+
+    /// Generate the expression of a discriminator value of the range, relative to its constant if it has one
+    /// The offsets are wrapping so that a range which doesn't fit in the repr only fails its assertion
+    fn value_expr(&self, value: i128) -> proc_macro2::TokenStream {
+        let offset = Literal::i128_unsuffixed(value.abs());
+        match self.base() {
+            None => {
+                let literal = Literal::i128_unsuffixed(value);
+                quote!(#literal)
             }
-            // remove the attribute after parsing it
-            variant.attrs.remove(index.unwrap());
+            Some(base) if value == 0 => quote!(#base),
+            Some(base) if value < 0 => quote!(#base.wrapping_sub(#offset)),
+            Some(base) => quote!(#base.wrapping_add(#offset)),
+        }
+    }
 
-            ranges.push_back((variant_index, range))
+    /// Writes a discriminator value of the range as it would be given in a bound, such as `BASE + 4`
+    fn value_doc(&self, value: i128) -> String {
+        match self.base() {
+            None => value.to_string(),
+            Some(base) if value == 0 => quote!(#base).to_string(),
+            Some(base) if value < 0 => format!("{} - {}", quote!(#base), -value),
+            Some(base) => format!("{} + {value}", quote!(#base)),
         }
     }
 
-    // No ranges, nothing to do
-    if ranges.is_empty() {
-        return quote!().into();
+    /// Gets the lowest discriminator value covered by the range
+    fn min(&self) -> i128 {
+        self.windows()
+            .into_iter()
+            .map(|(start, end)| start.min(end))
+            .min()
+            .unwrap_or_default()
     }
 
-    // This is the code for all the range_check generated
-    let mut enum_impl = quote!();
+    /// Gets the highest discriminator value covered by the range
+    fn max(&self) -> i128 {
+        self.windows()
+            .into_iter()
+            .map(|(start, end)| start.max(end))
+            .max()
+            .unwrap_or_default()
+    }
+}
 
-    // Make the list of new variants
-    let mut new_variants: Punctuated<Variant, Token![,]> = Punctuated::new();
+/// Represents the options of the main `#[enum_range(...)]` attribute, see the crate documentation
+#[derive(Debug, Default, FromMeta)]
+#[darling(default)]
+struct EnumRangeArgs {
+    repr: Option<Ident>,
+    format: Option<String>,
+    auto_checks: bool,
+    from_repr: bool,
+    saturating_from_repr: bool,
+    try_from: bool,
+    eq_repr: bool,
+    into_repr: bool,
+    from_str: bool,
+    as_str: bool,
+    as_str_fn: Option<String>,
+    value_fn: Option<String>,
+    display: bool,
+    debug_with_value: bool,
+    value_radix: Option<Radix>,
+    const_fns: bool,
+    vis: Option<Visibility>,
+    #[cfg(feature = "serde")]
+    serde: bool,
+    doc_values: bool,
+    names_table: bool,
+    names_table_name: Option<String>,
+    which_range_fn: Option<String>,
+    index_of_fn: Option<String>,
+    is_in_any_range_fn: Option<String>,
+    range_enum: bool,
+    range_enum_name: Option<String>,
+    try_from_error_name: Option<String>,
+    from_str_error_name: Option<String>,
+    parse_error_name: Option<String>,
+    discriminant_table: bool,
+    #[darling(multiple)]
+    category: Vec<Category>,
+    matches_category_fn: Option<String>,
+    category_names_const: Option<String>,
+    max_variants: Option<usize>,
+    wrap_module: Option<String>,
+}
 
-    let mut current_range = ranges.pop_front();
-    for (index, variant) in data_enum.variants.iter().enumerate() {
+/// A range of values checked by the generated methods without generating variants
+#[derive(Debug, FromMeta)]
+struct Category {
+    #[darling(with = parse_category_name)]
+    name: String,
+    start: Bound,
+    end: Bound,
+}
 
-        if let Some((range_idx, range)) = &current_range {
-            if index < *range_idx {
-                // current variant is before the next variant-range to generate so we keep it as is
-                new_variants.push(variant.clone());
-            } else if index == *range_idx {
-                // This variant needs to be replaced by a range
-                for range_index in 0..range.end - range.start + 1 {
-                    let range_value = range.start + range_index;
-                    new_variants.push(Variant {
-                        attrs: variant.attrs.clone(),
-                        ident: generate_variant_ident(variant, range, range_index, range_value),
-                        fields: Fields::Unit,
-                        discriminant: Some((
-                            syn::parse_str("=").unwrap(),
-                            syn::parse_str(&range_value.to_string()).unwrap(),
-                        )),
-                    })
-                }
+/// A bound of a range, given as an integer or a character literal,
+/// or as a constant plus or minus integers such as `BASE + 4`
+#[derive(Debug, Default, Clone)]
+struct Bound {
+    /// The discriminator value, the code point for a character, or the offset from `base`
+    value: i128,
+    is_char: bool,
+    /// The constant the value is relative to
+    base: Option<Expr>,
+}
 
-                // Generate the associated range checker if we can
-                let range_checker = generate_range_checker(enum_ident, &repr, range);
-                enum_impl = quote! {
-                    #enum_impl
+impl FromMeta for Bound {
+    fn from_expr(expr: &Expr) -> darling::Result<Self> {
+        match expr {
+            // `-5` is an unary expression, not a literal
+            Expr::Unary(ExprUnary {
+                op: UnOp::Neg(_),
+                expr,
+                ..
+            }) => i128::from_expr(expr).map(|value| Bound {
+                value: -value,
+                is_char: false,
+                base: None,
+            }),
+            Expr::Lit(ExprLit {
+                lit: Lit::Char(char),
+                ..
+            }) => Ok(Bound {
+                value: char.value() as i128,
+                is_char: true,
+                base: None,
+            }),
+            // a constant such as `BASE` or `u8::MAX`, its value is only known by the compiler
+            Expr::Path(_) => Ok(Bound {
+                value: 0,
+                is_char: false,
+                base: Some(expr.clone()),
+            }),
+            Expr::Binary(ExprBinary {
+                left,
+                op: op @ (BinOp::Add(_) | BinOp::Sub(_)),
+                right,
+                ..
+            }) => {
+                let left = Self::from_expr(left)?;
+                let right = Self::from_expr(right)?;
+                if left.is_char || right.is_char {
+                    return Err(darling::Error::custom(
+                        "character bounds can't be computed, use a character literal",
+                    )
+                    .with_span(expr));
+                }
 
-                    #range_checker
+                let subtract = matches!(op, BinOp::Sub(_));
+                let base = match (left.base, right.base) {
+                    (base, None) => base,
+                    (None, Some(base)) if !subtract => Some(base),
+                    _ => {
+                        return Err(darling::Error::custom(
+                            "a bound can only add integers to a single constant or subtract integers from it",
+                        )
+                        .with_span(expr))
+                    }
+                };
+                let value = if subtract {
+                    left.value - right.value
+                } else {
+                    left.value + right.value
                 };
 
-                current_range = ranges.pop_front();
-            } else {
-                // We can't be after the next range to generate because if we pass over a range we get the next one
-                // which and they have the same ordering
-                unreachable!()
+                Ok(Bound {
+                    value,
+                    is_char: false,
+                    base,
+                })
             }
-        } else {
-            // We are done processing ranges, just add the final normal variants
-            new_variants.push(variant.clone());
+            Expr::Group(group) => Self::from_expr(&group.expr),
+            Expr::Paren(paren) => Self::from_expr(&paren.expr),
+            expr => i128::from_expr(expr).map(|value| Bound {
+                value,
+                is_char: false,
+                base: None,
+            }),
         }
     }
+}
 
-    // Change the enum definition in place
-    data_enum.variants = new_variants;
+/// Checks if two bounds are relative to the same constant, or are both literals
+fn same_base(a: &Option<Expr>, b: &Option<Expr>) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => quote!(#a).to_string() == quote!(#b).to_string(),
+        (a, b) => a.is_none() && b.is_none(),
+    }
+}
 
-    enum_impl
+/// Parses the name of a category, which names its `is_{name}` method
+fn parse_category_name(meta: &Meta) -> darling::Result<String> {
+    let name = String::from_meta(meta)?;
+    generated_name(
+        Some(&format!("is_{name}")),
+        "category method",
+        format_ident!("is"),
+    )
+    .map_err(|err| darling::Error::custom(err).with_span(meta))?;
+    Ok(name)
 }
 
-/// Generate a method for a range that checks if an enum variant is in it
-fn generate_range_checker(
-    enum_ident: &Ident,
-    repr: &Option<Ident>,
-    range: &Range,
-) -> Option<proc_macro2::TokenStream> {
-    if range.range_check.is_none() || repr.is_none() {
-        return None;
+/// Parses a signed integer, which darling only accepts between quotes when it is negative
+fn parse_signed(meta: &Meta) -> darling::Result<Option<isize>> {
+    let bound = Bound::from_meta(meta)?;
+    if bound.is_char {
+        return Err(darling::Error::unexpected_type("char").with_span(meta));
     }
 
-    let range_start = Literal::from_str(&range.start.to_string()).unwrap();
-    let range_end = Literal::from_str(&range.end.to_string()).unwrap();
-    let method_name = format_ident!("{}", range.range_check.as_ref().unwrap());
+    isize::try_from(bound.value)
+        .map(Some)
+        .map_err(|_| darling::Error::custom("value out of range").with_span(meta))
+}
 
-    return Some(quote! {
-        impl #enum_ident {
-            pub fn #method_name(self) -> bool {
-                let value = self as #repr;
-                value >= #range_start && value <= #range_end
+/// Parses a list of intervals such as `[(10..=20), (30..40)]`, the end of an interval is excluded with `..`
+fn parse_windows(meta: &Meta) -> darling::Result<Option<Vec<(Bound, Bound)>>> {
+    let Expr::Array(array) = &meta.require_name_value()?.value else {
+        let expr = &meta.require_name_value()?.value;
+        return Err(darling::Error::unexpected_expr_type(expr).with_span(expr));
+    };
+
+    array
+        .elems
+        .iter()
+        .map(|expr| {
+            let mut window = expr;
+            while let Expr::Paren(ExprParen { expr, .. }) | Expr::Group(ExprGroup { expr, .. }) =
+                window
+            {
+                window = expr;
             }
-        }
-    });
+            let Expr::Range(ExprRange {
+                start: Some(start),
+                end: Some(end),
+                limits,
+                ..
+            }) = window
+            else {
+                return Err(darling::Error::custom(
+                    "expected a range with a start and an end, e.g. `(10..=20)`",
+                )
+                .with_span(expr));
+            };
+
+            let start = Bound::from_expr(start)?;
+            let mut end = Bound::from_expr(end)?;
+            if let RangeLimits::HalfOpen(_) = limits {
+                if end.value == start.value {
+                    return Err(darling::Error::custom(
+                        "range is empty because its end is excluded",
+                    )
+                    .with_span(expr));
+                }
+                // the last value is the one before the end, going from the start
+                end.value += if end.value > start.value { -1 } else { 1 };
+            }
+
+            Ok((start, end))
+        })
+        .collect::<darling::Result<Vec<_>>>()
+        .map(Some)
 }
 
-/// Generate the [Ident] for an enum variant in a range
-fn generate_variant_ident(variant: &Variant, range: &Range, index: usize, value: usize) -> Ident {
-    let format = range
-        .format
-        .clone()
-        .unwrap_or_else(|| format!("{}{{}}", variant.ident));
+/// Parses a list of bounds such as `[3, -1, 'a']`
+fn parse_bound_list(meta: &Meta) -> darling::Result<Option<Vec<Bound>>> {
+    match &meta.require_name_value()?.value {
+        Expr::Array(array) => array
+            .elems
+            .iter()
+            .map(Bound::from_expr)
+            .collect::<darling::Result<Vec<_>>>()
+            .map(Some),
+        expr => Err(darling::Error::unexpected_expr_type(expr).with_span(expr)),
+    }
+}
 
-    let ident_str = format
-        .replace("{index}", &index.to_string())
-        .replace("{value}", &value.to_string());
-    Ident::new(&ident_str, Span::call_site())
+/// Main derive attribute macro. `#[enum_range]` must be applied before any other derives because it changes the definition of the enum
+/// The `#[range(...)]` attribute and the options are described in the [crate documentation](crate)
+///
+/// Applying it to a struct or a union is a compile error:
+///
+/// ```compile_fail
+/// use enum_range::enum_range;
+///
+/// #[enum_range]
+/// struct NotAnEnum {
+///     field: u8,
+/// }
+/// ```
+///
+/// The derives placed before `#[enum_range]` are expanded first, on the enum as it is written. They then name the
+/// annotated variants instead of the generated ones and either fail to compile or silently work on the wrong variants.
+/// The macro can't detect nor fix this since the compiler removes these derives from the enum it receives:
+///
+/// ```compile_fail
+/// use enum_range::enum_range;
+///
+/// #[derive(Debug)]
+/// #[enum_range]
+/// #[repr(u8)]
+/// enum Misplaced {
+///     Zero,
+///     #[range(start = 1, end = 3)]
+///     Value,
+/// }
+/// ```
+///
+/// Placing the derives after it works:
+///
+/// ```rust
+/// use enum_range::enum_range;
+///
+/// #[enum_range]
+/// #[derive(Debug)]
+/// #[repr(u8)]
+/// enum Placed {
+///     #[range(start = 1, end = 3)]
+///     Value,
+/// }
+///
+/// assert_eq!(format!("{:?}", Placed::Value2), "Value2");
+/// ```
+///
+/// `#[non_exhaustive]` enums are supported: the generated items are part of the crate defining the enum, where
+/// its variants are known, and the conversions from values always have a fallback for unknown values.
+/// Outside of the crate, the items work as for any other enum:
+///
+/// ```rust
+/// mod protocol {
+///     use enum_range::enum_range;
+///
+///     #[enum_range(from_repr, try_from, display)]
+///     #[repr(u8)]
+///     #[non_exhaustive]
+///     #[derive(Debug, PartialEq)]
+///     pub enum Message {
+///         Hello = 0,
+///         #[range(format = "Vendor{index}", start = 0xF0, end = 0xF3, range_check = "is_vendor")]
+///         Vendor,
+///     }
+/// }
+///
+/// use protocol::Message;
+///
+/// let message = Message::from_repr(0xF1).unwrap();
+/// assert!(message.is_vendor());
+/// assert_eq!(message.which_range(), Some("vendor"));
+/// assert_eq!(Message::try_from(0x10).map_err(|err| err.0), Err(0x10));
+/// match message {
+///     Message::Hello => unreachable!(),
+///     other => assert_eq!(other.to_string(), "Vendor1"),
+/// }
+/// ```
+///
+/// The numerical repr is found among the other arguments of `#[repr(...)]`:
+///
+/// ```rust
+/// use enum_range::enum_range;
+///
+/// #[enum_range]
+/// #[repr(u8, align(4))]
+/// enum Aligned {
+///     #[range(start = 1, end = 3, range_check = "is_low")]
+///     Low,
+/// }
+///
+/// assert!(Aligned::Low1.is_low());
+/// assert_eq!(std::mem::align_of::<Aligned>(), 4);
+/// ```
+///
+/// The pointer-sized reprs `usize` and `isize` are numerical reprs too:
+///
+/// ```rust
+/// use enum_range::enum_range;
+///
+/// #[enum_range(from_repr)]
+/// #[repr(usize)]
+/// #[derive(Debug, PartialEq)]
+/// enum Slot {
+///     #[range(start = 0, end = 3, range_check = "is_low")]
+///     Low,
+///     High = 100,
+/// }
+///
+/// #[enum_range]
+/// #[repr(isize)]
+/// enum Offset {
+///     #[range(format = "Back{index}", start = -2, end = -1, range_check = "is_back")]
+///     Back,
+///     #[range(format = "Forward{index}", start = 0, end = 2, range_check = "is_forward")]
+///     Forward,
+/// }
+///
+/// assert!(Slot::Low3.is_low());
+/// assert!(!Slot::High.is_low());
+/// assert_eq!(Slot::from_repr(2), Some(Slot::Low2));
+/// assert_eq!(Slot::low_bounds(), 0usize..=3);
+/// assert_eq!(Offset::Back0.value(), -2isize);
+/// assert!(Offset::is_back_value(-1));
+/// assert!(Offset::Forward2.is_forward());
+/// assert_eq!(Offset::BACK_MIN, -2);
+/// ```
+///
+/// Values which don't fit in the repr are compile errors:
+///
+/// ```compile_fail
+/// use enum_range::enum_range;
+///
+/// #[enum_range]
+/// #[repr(usize)]
+/// enum Negative {
+///     #[range(start = -2, end = 1)]
+///     Value,
+/// }
+/// ```
+///
+/// The numerical repr can also be given in its own attribute:
+///
+/// ```rust
+/// use enum_range::enum_range;
+///
+/// #[enum_range]
+/// #[repr(align(2))]
+/// #[repr(u8)]
+/// enum Split {
+///     #[range(start = 1, end = 3, range_check = "is_low")]
+///     Low,
+/// }
+///
+/// assert!(Split::Low2.is_low());
+/// ```
+///
+/// Other reprs such as `C` or `transparent` aren't numerical reprs, so the options requiring one are a compile error:
+///
+/// ```compile_fail
+/// use enum_range::enum_range;
+///
+/// #[enum_range(from_repr)]
+/// #[repr(C)]
+/// enum NotNumerical {
+///     #[range(start = 1, end = 3, range_check = "is_low")]
+///     Low,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use enum_range::enum_range;
+///
+/// #[enum_range(from_repr)]
+/// #[repr(transparent)]
+/// enum NotNumerical {
+///     #[range(start = 1, end = 1, range_check = "is_low")]
+///     Low,
+/// }
+/// ```
+///
+/// Floats aren't enum reprs, using one is a compile error:
+///
+/// ```compile_fail
+/// use enum_range::enum_range;
+///
+/// #[enum_range]
+/// #[repr(f32)]
+/// enum Float {
+///     #[range(start = 1, end = 3)]
+///     Value,
+/// }
+/// ```
+///
+/// A malformed `repr` attribute is a compile error:
+///
+/// ```compile_fail
+/// use enum_range::enum_range;
+///
+/// #[enum_range]
+/// #[repr = "u8"]
+/// enum Malformed {
+///     #[range(start = 1, end = 3)]
+///     Low,
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn enum_range(args: TokenStream, input: TokenStream) -> TokenStream {
+    let mut ast = parse_macro_input!(input as DeriveInput);
+
+    let args = match NestedMeta::parse_meta_list(args.into())
+        .map_err(darling::Error::from)
+        .and_then(|items| EnumRangeArgs::from_list(&items))
+    {
+        Ok(args) => args,
+        Err(err) => return err.write_errors().into(),
+    };
+
+    let repr = match get_repr(&ast).and_then(|repr| apply_repr_arg(&mut ast, repr, &args)) {
+        Ok(repr) => repr,
+        Err(err) => return err.to_compile_error().into(),
+    };
+
+    let generated = match ast.data {
+        Data::Enum(ref mut data_enum) => {
+            match generate_enum(data_enum, &ast.ident, &ast.generics, &ast.vis, &repr, &args) {
+                Ok(generated) => generated,
+                Err(err) => return err.to_compile_error().into(),
+            }
+        }
+        Data::Struct(_) | Data::Union(_) => {
+            return syn::Error::new_spanned(
+                &ast.ident,
+                "enum_range can only be applied to enum types",
+            )
+            .to_compile_error()
+            .into();
+        }
+    };
+
+    let result = quote! {
+        #ast
+
+        #generated
+    };
+
+    result.into()
+}
+
+/// Derives the checks of the ranges of an enum without changing it, the subset of `#[enum_range]`
+/// which doesn't need to generate variants.
+///
+/// Unlike the attribute, the derive can be listed along with the other derives in any order.
+/// Its `#[range(...)]` attributes take the same options as those of `#[enum_range]`, and are all
+/// `variants = false` ranges: the annotated variant is kept as is, and the range only generates its
+/// `range_check` on the values of the range. The variants of the range have to be declared by hand,
+/// and the enum-wide options of `#[enum_range(...)]` aren't available. Being the helper attribute of the derive,
+/// `#[range(...)]` can't be spelled `#[enum_range::range(...)]` there.
+///
+/// ```rust
+/// use enum_range::EnumRange;
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, EnumRange)]
+/// #[repr(u8)]
+/// enum Status {
+///     Ok = 0,
+///     #[range(start = 100, end = 199, range_check = "is_client_error")]
+///     ClientError = 100,
+///     NotFound = 104,
+///     #[range(start = 200, end = 255, range_check = "is_server_error")]
+///     ServerError = 200,
+/// }
+///
+/// assert!(Status::NotFound.is_client_error());
+/// assert!(Status::ClientError.is_client_error());
+/// assert!(!Status::Ok.is_client_error());
+/// assert!(Status::ServerError.is_server_error());
+/// assert!(Status::is_server_error_value(250));
+/// assert_eq!(Status::CLIENT_ERROR_MIN, 100);
+/// assert_eq!(Status::client_error_bounds(), 100..=199);
+/// ```
+///
+/// Its ranges can't generate variants:
+///
+/// ```compile_fail
+/// use enum_range::EnumRange;
+///
+/// #[derive(EnumRange)]
+/// #[repr(u8)]
+/// enum Status {
+///     #[range(start = 100, end = 199, range_check = "is_client_error", variants = true)]
+///     ClientError,
+/// }
+/// ```
+#[proc_macro_derive(EnumRange, attributes(range))]
+pub fn derive_enum_range(input: TokenStream) -> TokenStream {
+    let mut ast = parse_macro_input!(input as DeriveInput);
+
+    let repr = match get_repr(&ast) {
+        Ok(repr) => repr,
+        Err(err) => return err.to_compile_error().into(),
+    };
+
+    let args = EnumRangeArgs::default();
+    match ast.data {
+        // the enum is changed in a copy, the derive only outputs the checks
+        Data::Enum(ref mut data_enum) => {
+            match generate_enum_ranges(data_enum, &ast.ident, &ast.generics, &repr, &args, true) {
+                Ok((checks, _)) => checks.into(),
+                Err(err) => err.to_compile_error().into(),
+            }
+        }
+        Data::Struct(_) | Data::Union(_) => {
+            syn::Error::new_spanned(&ast.ident, "EnumRange can only be derived for enum types")
+                .to_compile_error()
+                .into()
+        }
+    }
+}
+
+/// Gives the enum the numerical repr of the `repr` option when it doesn't have one, which has to match otherwise
+fn apply_repr_arg(
+    ast: &mut DeriveInput,
+    repr: Option<Ident>,
+    args: &EnumRangeArgs,
+) -> syn::Result<Option<Ident>> {
+    let Some(arg) = &args.repr else {
+        return Ok(repr);
+    };
+
+    if repr_bounds(arg).is_none() {
+        return Err(syn::Error::new_spanned(
+            arg,
+            format!("`{arg}` is not a numerical repr, expected one of the integer types"),
+        ));
+    }
+
+    match repr {
+        Some(repr) if repr != *arg => Err(syn::Error::new_spanned(
+            arg,
+            format!(
+                "the repr option `{arg}` doesn't match the `#[repr({repr})]` attribute of the enum"
+            ),
+        )),
+        Some(repr) => Ok(Some(repr)),
+        None => {
+            ast.attrs.push(syn::parse_quote!(#[repr(#arg)]));
+            Ok(Some(arg.clone()))
+        }
+    }
+}
+
+/// Gets the first numerical representation associated to the enum, among all its `repr` attributes
+/// A `repr` attribute which isn't a list of arguments is an error
+fn get_repr(ast: &DeriveInput) -> syn::Result<Option<Ident>> {
+    let regex = Regex::new(r"^[ui](8|16|32|64|128|size)$").unwrap();
+
+    // The reprs can be split over multiple attributes, e.g. `#[repr(align(2))] #[repr(u8)]`
+    for attr in ast.attrs.iter() {
+        if !attr.path().is_ident("repr") {
+            continue;
+        }
+
+        let meta_list = attr.meta.require_list()?;
+
+        // The arguments can be paths like `u8` or `C` as well as lists like `align(4)`
+        let reprs = meta_list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+
+        // The discriminators are integers, reported here rather than in the generated code
+        if let Some(float) = reprs
+            .iter()
+            .filter_map(|repr| repr.path().get_ident())
+            .find(|repr| matches!(repr.to_string().as_str(), "f16" | "f32" | "f64" | "f128"))
+        {
+            return Err(syn::Error::new_spanned(
+                float,
+                format!("`{float}` is not a valid enum repr, the discriminators of an enum are integers"),
+            ));
+        }
+
+        if let Some(repr) = reprs
+            .iter()
+            .filter_map(|repr| repr.path().get_ident())
+            .find(|repr| regex.is_match(&repr.to_string()))
+        {
+            return Ok(Some(repr.clone()));
+        }
+    }
+
+    Ok(None)
+}
+
+/// Gets the values a numerical repr can hold, limited to what a range can express
+/// `usize` and `isize` take the values of the widest targets, the compiler rejects the overflowing values on the others
+fn repr_bounds(repr: &Ident) -> Option<(i128, i128)> {
+    let bounds = match repr.to_string().as_str() {
+        "u8" => (u8::MIN.into(), u8::MAX.into()),
+        "u16" => (u16::MIN.into(), u16::MAX.into()),
+        "u32" => (u32::MIN.into(), u32::MAX.into()),
+        "u64" => (u64::MIN.into(), u64::MAX.into()),
+        "u128" => (0, i128::MAX),
+        "i8" => (i8::MIN.into(), i8::MAX.into()),
+        "i16" => (i16::MIN.into(), i16::MAX.into()),
+        "i32" => (i32::MIN.into(), i32::MAX.into()),
+        "i64" => (i64::MIN.into(), i64::MAX.into()),
+        "i128" => (i128::MIN, i128::MAX),
+        "usize" => (u64::MIN.into(), u64::MAX.into()),
+        "isize" => (i64::MIN.into(), i64::MAX.into()),
+        _ => return None,
+    };
+
+    Some(bounds)
+}
+
+/// Generates the ranges of the enum and the enum-wide items requested in the options
+/// Changes the structure in place
+fn generate_enum(
+    data_enum: &mut DataEnum,
+    enum_ident: &Ident,
+    generics: &Generics,
+    vis: &Visibility,
+    repr: &Option<Ident>,
+    args: &EnumRangeArgs,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let (ranges, generated_ranges) =
+        generate_enum_ranges(data_enum, enum_ident, generics, repr, args, false)?;
+
+    let from_repr = if args.from_repr {
+        Some(generate_from_repr(
+            data_enum, enum_ident, generics, repr, args,
+        )?)
+    } else {
+        None
+    };
+
+    let saturating_from_repr = if args.saturating_from_repr {
+        Some(generate_saturating_from_repr(
+            data_enum, enum_ident, generics, repr, args,
+        )?)
+    } else {
+        None
+    };
+
+    let try_from = if args.try_from || args.try_from_error_name.is_some() {
+        Some(generate_try_from(
+            data_enum, enum_ident, generics, vis, repr, args,
+        )?)
+    } else {
+        None
+    };
+
+    let eq_repr = if args.eq_repr {
+        Some(generate_eq_repr(data_enum, enum_ident, generics, repr)?)
+    } else {
+        None
+    };
+
+    let into_repr = if args.into_repr {
+        Some(generate_into_repr(data_enum, enum_ident, generics, repr)?)
+    } else {
+        None
+    };
+
+    let from_str =
+        if args.from_str || args.from_str_error_name.is_some() || args.parse_error_name.is_some() {
+            Some(generate_from_str(
+                data_enum, enum_ident, generics, vis, args,
+            )?)
+        } else {
+            None
+        };
+
+    let as_str = if args.as_str || args.as_str_fn.is_some() {
+        Some(generate_as_str(data_enum, enum_ident, generics, args)?)
+    } else {
+        None
+    };
+
+    let value_fn = generate_value_fn(data_enum, enum_ident, generics, repr, args)?;
+
+    let names_table = if args.names_table || args.names_table_name.is_some() {
+        Some(generate_names_table(
+            data_enum, enum_ident, generics, repr, args,
+        )?)
+    } else {
+        None
+    };
+
+    let discriminant_table = if args.discriminant_table {
+        Some(generate_discriminant_table(
+            data_enum, enum_ident, generics, repr, args,
+        )?)
+    } else {
+        None
+    };
+
+    let categories = args
+        .category
+        .iter()
+        .map(|category| generate_category(data_enum, enum_ident, generics, repr, args, category))
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let wrap_module = match &args.wrap_module {
+        Some(module) => Some(generate_wrap_module(
+            data_enum, enum_ident, generics, vis, module,
+        )?),
+        None => None,
+    };
+
+    let bits = generate_bits(enum_ident, generics, repr, args, &generated_ranges)?;
+
+    let default = generate_default(enum_ident, generics, &generated_ranges)?;
+
+    let range_lookups =
+        generate_range_lookups(enum_ident, generics, repr, args, &generated_ranges)?;
+
+    let range_enum = if args.range_enum || args.range_enum_name.is_some() {
+        Some(generate_range_enum(
+            enum_ident,
+            generics,
+            vis,
+            args,
+            &generated_ranges,
+        )?)
+    } else {
+        None
+    };
+
+    let category_matches = generate_category_matches(
+        data_enum,
+        enum_ident,
+        generics,
+        repr,
+        args,
+        &generated_ranges,
+    )?;
+
+    let display = if args.display {
+        Some(generate_display(
+            data_enum,
+            enum_ident,
+            generics,
+            &generated_ranges,
+        ))
+    } else {
+        None
+    };
+
+    let debug = if args.debug_with_value {
+        Some(generate_debug(data_enum, enum_ident, generics, repr, args)?)
+    } else {
+        None
+    };
+
+    #[cfg(feature = "serde")]
+    let serde = if args.serde {
+        Some(generate_serde(data_enum, enum_ident, generics, repr)?)
+    } else {
+        None
+    };
+    #[cfg(not(feature = "serde"))]
+    let serde: Option<proc_macro2::TokenStream> = None;
+
+    Ok(quote! {
+        #ranges
+
+        #from_repr
+
+        #saturating_from_repr
+
+        #try_from
+
+        #eq_repr
+
+        #into_repr
+
+        #from_str
+
+        #as_str
+
+        #value_fn
+
+        #names_table
+
+        #discriminant_table
+
+        #range_lookups
+
+        #range_enum
+
+        #category_matches
+
+        #(#categories)*
+
+        #bits
+
+        #default
+
+        #wrap_module
+
+        #display
+
+        #debug
+
+        #serde
+    })
+}
+
+/// Generates the variants for each range defined on the enum, or only their checks with `checks_only`
+/// Changes the structure in place
+fn generate_enum_ranges(
+    data_enum: &mut DataEnum,
+    enum_ident: &Ident,
+    generics: &Generics,
+    repr: &Option<Ident>,
+    args: &EnumRangeArgs,
+    checks_only: bool,
+) -> syn::Result<(proc_macro2::TokenStream, Vec<GeneratedRange>)> {
+    let mut ranges = VecDeque::new();
+
+    // Find all ranges defined in the enum
+    for (variant_index, variant) in data_enum.variants.iter_mut().enumerate() {
+        for attr in variant.attrs.iter_mut().filter(|attr| is_range_attr(attr)) {
+            desugar_range_path(attr);
+            desugar_trait_key(attr);
+            desugar_range_expr(attr)?;
+        }
+
+        // extract "range" attribute
+        if let Some(index) = variant
+            .attrs
+            .iter()
+            .position(|attr| attr.path().is_ident("range"))
+        {
+            let mut range = Range::parse(variant, &variant.attrs[index])?;
+            // remove the attribute after parsing it
+            let attr = variant.attrs.remove(index);
+            // the values of the range are listed to infer the start of the next ones, it needs an end
+            range.validate_extent(&attr)?;
+
+            // the ranges without a format take the one of the enum
+            if range.format.is_none() {
+                range.format = args.format.clone();
+            }
+            if args.auto_checks && range.range_check.is_none() {
+                let name = Case::Snake.apply(&variant.ident.unraw().to_string());
+                range.range_check = Some(format!("is_{name}"));
+            }
+
+            // a derive can't change the enum, its ranges only generate their checks
+            if checks_only {
+                if range.variants == Some(true) {
+                    return Err(syn::Error::new_spanned(
+                        attr,
+                        "#[derive(EnumRange)] can't generate the variants of a range, use the #[enum_range] attribute instead",
+                    ));
+                }
+                if range.checker_only {
+                    return Err(syn::Error::new_spanned(
+                        attr,
+                        "#[derive(EnumRange)] can't remove the variant of a checker_only range, use variants = false instead",
+                    ));
+                }
+                range.variants = Some(false);
+            }
+
+            // the discriminators of the generated variants come from the range
+            if let Some((_, discriminant)) = variant
+                .discriminant
+                .as_ref()
+                .filter(|_| !range.keeps_variant())
+            {
+                return Err(syn::Error::new_spanned(
+                    discriminant,
+                    format!(
+                        "range variant `{}` can't have a discriminator, the values of its variants are given by the range",
+                        variant.ident
+                    ),
+                ));
+            }
+
+            ranges.push_back((variant_index, range, attr))
+        }
+    }
+
+    // No ranges, only the items of the enum such as its categories can use the discriminator method
+    if ranges.is_empty() {
+        let discriminant_fn =
+            generate_discriminant_fn_if_valid(data_enum, enum_ident, generics, repr);
+        return Ok((quote!(#discriminant_fn), Vec::new()));
+    }
+
+    prepare_ranges(
+        data_enum,
+        &mut ranges,
+        args.max_variants.unwrap_or(DEFAULT_MAX_VARIANTS),
+    )?;
+    for (_, range, attr) in ranges.iter() {
+        range.validate(attr, repr)?;
+    }
+
+    // Rust only allows discriminators on variants with fields when the enum has a primitive repr
+    let assign_discriminants = repr.is_some() || is_fieldless(data_enum);
+    check_overlaps(data_enum, &ranges, assign_discriminants)?;
+
+    // The ranges once their variants are generated
+    let mut generated_ranges = Vec::new();
+
+    // Make the list of new variants
+    let mut new_variants: Punctuated<Variant, Token![,]> = Punctuated::new();
+
+    // Names of all the variants, to detect a format generating the same name multiple times
+    let mut variant_idents: HashSet<Ident> = data_enum
+        .variants
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| {
+            !ranges
+                .iter()
+                .any(|(range_idx, range, _)| range_idx == index && !range.keeps_variant())
+        })
+        .map(|(_, variant)| variant.ident.clone())
+        .collect();
+
+    let mut current_range = ranges.pop_front();
+    for (index, variant) in data_enum.variants.iter().enumerate() {
+        if let Some((range_idx, range, attr)) = &current_range {
+            if index < *range_idx {
+                // current variant is before the next variant-range to generate so we keep it as is
+                new_variants.push(variant.clone());
+            } else if index == *range_idx && !range.generates_variants() {
+                // The range only generates its checks, the variant is kept as is unless the range is checker_only
+                if range.keeps_variant() {
+                    new_variants.push(variant.clone());
+                }
+                let (_, range, attr) =
+                    std::mem::replace(&mut current_range, ranges.pop_front()).unwrap();
+                generated_ranges.push(GeneratedRange {
+                    range,
+                    attr,
+                    variant: variant.clone(),
+                    idents: Vec::new(),
+                    displays: Vec::new(),
+                });
+            } else if index == *range_idx {
+                // This variant needs to be replaced by a range
+                let context = RangeContext {
+                    variant,
+                    range,
+                    repr,
+                    attr,
+                };
+
+                let mut range_idents = Vec::new();
+                let mut range_displays = Vec::new();
+                for (range_index, range_value) in range.values().into_iter().enumerate() {
+                    let ident = generate_variant_ident(&context, range_index, range_value)?;
+                    range_displays.push(match &range.display {
+                        Some(display) => {
+                            format_placeholders(&context, display, range_index, range_value, "-")?
+                        }
+                        None => ident.unraw().to_string(),
+                    });
+                    if !variant_idents.insert(ident.clone()) {
+                        return Err(syn::Error::new_spanned(
+                            attr,
+                            format!("range format produced the duplicate variant name `{ident}`, use the `{{index}}` or `{{value}}` placeholders to generate distinct names"),
+                        ));
+                    }
+
+                    let mut attrs = generate_variant_attrs(&context, range_index, range_value)?;
+                    if !is_camel_case(&ident.unraw().to_string()) {
+                        attrs.push(syn::parse_quote!(#[allow(non_camel_case_types)]));
+                    }
+                    if args.doc_values {
+                        let doc = format!(" value = {}", range.value_doc(range_value));
+                        attrs.push(syn::parse_quote!(#[doc = #doc]));
+                    }
+
+                    range_idents.push(ident.clone());
+                    new_variants.push(Variant {
+                        attrs,
+                        ident,
+                        fields: variant.fields.clone(),
+                        discriminant: assign_discriminants
+                            .then(|| (Default::default(), discriminant_expr(range, range_value))),
+                    })
+                }
+
+                let (_, range, attr) =
+                    std::mem::replace(&mut current_range, ranges.pop_front()).unwrap();
+                generated_ranges.push(GeneratedRange {
+                    range,
+                    attr,
+                    variant: variant.clone(),
+                    idents: range_idents,
+                    displays: range_displays,
+                });
+            } else {
+                // We can't be after the next range to generate because if we pass over a range we get the next one
+                // which and they have the same ordering
+                unreachable!()
+            }
+        } else {
+            // We are done processing ranges, just add the final normal variants
+            new_variants.push(variant.clone());
+        }
+    }
+
+    // Change the enum definition in place
+    data_enum.variants = new_variants;
+
+    // The compiler only knows the values relative to a constant, their bounds are asserted to fit in the repr
+    let repr_asserts = repr.as_ref().map(|repr| {
+        generated_ranges
+            .iter()
+            .filter_map(|generated| Some((generated, generated.range.base()?)))
+            .map(|(generated, base)| {
+                let message = format!(
+                    "range `{}` doesn't fit in the enum repr `{repr}`",
+                    generated.variant.ident.unraw()
+                );
+                let fits =
+                    fits_in_repr(repr, &base, [generated.range.min(), generated.range.max()]);
+                let cfgs = cfg_attrs(&generated.variant);
+                quote! {
+                    #(#cfgs)*
+                    const _: () = ::core::assert!(#fits, #message);
+                }
+            })
+            .collect::<Vec<_>>()
+    });
+
+    // Each trait can only be implemented once, by the range_check of a single range
+    let mut traits = HashSet::new();
+    for generated in generated_ranges.iter() {
+        let Some(range_trait) = &generated.range.range_trait else {
+            continue;
+        };
+        if generated.range.range_check.is_none() {
+            return Err(syn::Error::new_spanned(
+                &generated.attr,
+                "range trait requires a range_check, the method implementing the trait",
+            ));
+        }
+        if !traits.insert(range_trait.to_token_stream().to_string()) {
+            return Err(syn::Error::new_spanned(
+                &generated.attr,
+                format!(
+                    "range trait `{}` is already implemented by the range_check of another range",
+                    range_trait.to_token_stream()
+                ),
+            ));
+        }
+    }
+
+    // Generate the associated range checkers if we can
+    let range_checkers = generated_ranges
+        .iter()
+        .map(|generated| {
+            generate_range_checker(data_enum, enum_ident, generics, repr, args, generated)
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let discriminant_fn = generate_discriminant_fn_if_valid(data_enum, enum_ident, generics, repr);
+
+    let repr_asserts = repr_asserts.unwrap_or_default();
+    Ok((
+        quote! {
+            #(#repr_asserts)*
+
+            #discriminant_fn
+
+            #(#range_checkers)*
+        },
+        generated_ranges,
+    ))
+}
+
+/// Generate the constant expression checking that offsets from a constant fit in the repr
+fn fits_in_repr(repr: &Ident, base: &Expr, offsets: [i128; 2]) -> proc_macro2::TokenStream {
+    let checks = offsets.map(|offset| {
+        let literal = Literal::i128_unsuffixed(offset.abs());
+        if offset < 0 {
+            quote!(<#repr>::checked_sub(#base, #literal).is_some())
+        } else {
+            quote!(<#repr>::checked_add(#base, #literal).is_some())
+        }
+    });
+    quote!(#(#checks)&&*)
+}
+
+/// Gives the ranges without a `start` the value following the previous variant, as rustc does for the variants
+/// without a discriminator, and checks that they don't have more than `max_variants` values before listing them
+fn prepare_ranges(
+    data_enum: &DataEnum,
+    ranges: &mut VecDeque<(usize, Range, Attribute)>,
+    max_variants: usize,
+) -> syn::Result<()> {
+    // The value of the next variant without a discriminator along with the constant it is relative to,
+    // unknown after a variant whose value isn't known
+    let mut next = Some((None, 0));
+
+    for (index, variant) in data_enum.variants.iter().enumerate() {
+        next = match ranges
+            .iter_mut()
+            .find(|(range_idx, _, _)| *range_idx == index)
+        {
+            Some((_, range, attr)) => {
+                if range.start.is_none() && range.ranges.is_none() {
+                    let Some((base, value)) = next.clone() else {
+                        return Err(syn::Error::new_spanned(
+                            attr,
+                            "range start can't be inferred since the value of the previous variant isn't known, give the range a start",
+                        ));
+                    };
+                    range.start = Some(Bound {
+                        value,
+                        is_char: false,
+                        base,
+                    });
+                }
+                if range.checker_only {
+                    // the annotated variant is removed and doesn't take a value
+                    next
+                } else if !range.generates_variants() {
+                    // the annotated variant is kept and takes its own value or the next one
+                    match &variant.discriminant {
+                        Some((_, expr)) => literal_value(expr)
+                            .and_then(|value| value.checked_add(1))
+                            .map(|value| (None, value)),
+                        None => next.and_then(|(base, value)| {
+                            value.checked_add(1).map(|value| (base, value))
+                        }),
+                    }
+                } else {
+                    let count = range.values_count();
+                    if count > max_variants as u128 {
+                        return Err(syn::Error::new_spanned(
+                            attr,
+                            format!("range would generate {count} variants, more than the limit of {max_variants} set by the max_variants option. Use a step, or a category to check the values without generating variants"),
+                        ));
+                    }
+                    match range.values().last() {
+                        Some(value) => value.checked_add(1).map(|value| (range.base(), value)),
+                        // a range without values doesn't change the value of the next variant
+                        None => next,
+                    }
+                }
+            }
+            None => match &variant.discriminant {
+                Some((_, expr)) => literal_value(expr)
+                    .and_then(|value| value.checked_add(1))
+                    .map(|value| (None, value)),
+                None => {
+                    next.and_then(|(base, value)| value.checked_add(1).map(|value| (base, value)))
+                }
+            },
+        };
+
+        // A variant removed by its cfg doesn't take its value, so the value of the next one isn't known
+        if cfg_attrs(variant).next().is_some() {
+            next = None;
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks if an attribute is a `#[range(...)]` attribute, also spelled `#[enum_range::range(...)]`
+fn is_range_attr(attr: &Attribute) -> bool {
+    let path = attr.path();
+    let segments = path
+        .segments
+        .iter()
+        .map(|segment| segment.ident.to_string())
+        .collect::<Vec<_>>();
+    path.is_ident("range") || segments == ["enum_range", "range"]
+}
+
+/// Rewrites the `#[enum_range::range(...)]` attributes to `#[range(...)]`, the only path darling looks for
+fn desugar_range_path(attr: &mut Attribute) {
+    let span = attr
+        .path()
+        .segments
+        .last()
+        .map(|segment| segment.ident.span());
+    if let (Meta::List(list), Some(span)) = (&mut attr.meta, span) {
+        list.path = Ident::new("range", span).into();
+    }
+}
+
+/// Renames the `trait` argument of a `#[range(...)]` attribute to `range_trait`, since a keyword can't be parsed as
+/// the name of an argument by darling
+fn desugar_trait_key(attr: &mut Attribute) {
+    let Meta::List(list) = &mut attr.meta else {
+        return;
+    };
+    let mut after_comma = true;
+    list.tokens = list
+        .tokens
+        .clone()
+        .into_iter()
+        .map(|token| {
+            let token = match token {
+                proc_macro2::TokenTree::Ident(ident) if after_comma && ident == "trait" => {
+                    proc_macro2::TokenTree::Ident(Ident::new("range_trait", ident.span()))
+                }
+                token => token,
+            };
+            after_comma =
+                matches!(&token, proc_macro2::TokenTree::Punct(punct) if punct.as_char() == ',');
+            token
+        })
+        .collect();
+}
+
+/// Rewrites a leading range expression of a `#[range(...)]` attribute, `start..=end` or `start..end`,
+/// into the `start = ..., end = ...` arguments parsed by darling, with `end_exclusive` for `start..end`
+fn desugar_range_expr(attr: &mut Attribute) -> syn::Result<()> {
+    let Meta::List(list) = &mut attr.meta else {
+        return Ok(());
+    };
+    // The arguments which can't be parsed are left to darling to report
+    let Ok(mut args) = list.parse_args_with(Punctuated::<Expr, Token![,]>::parse_terminated) else {
+        return Ok(());
+    };
+    let Some(Expr::Range(range)) = args.first() else {
+        return Ok(());
+    };
+
+    let (Some(start), Some(end)) = (&range.start, &range.end) else {
+        return Err(syn::Error::new_spanned(
+            range,
+            "the range syntax requires both a start and an end, e.g. `0..=10`",
+        ));
+    };
+    let mut bounds: Vec<Expr> = vec![
+        syn::parse_quote!(start = #start),
+        syn::parse_quote!(end = #end),
+    ];
+    if let RangeLimits::HalfOpen(_) = range.limits {
+        bounds.push(syn::parse_quote!(end_exclusive));
+    }
+    args = bounds.into_iter().chain(args.into_iter().skip(1)).collect();
+    list.tokens = quote!(#args);
+
+    Ok(())
+}
+
+/// Generate the discriminator expression of a variant
+/// The literal has no type suffix so that it takes the type of the enum repr
+fn discriminant_expr(range: &Range, value: i128) -> Expr {
+    let value = range.value_expr(value);
+    syn::parse_quote!(#value)
+}
+
+/// Checks that every discriminator value of the enum is only used once, by a range or by a normal variant
+/// The values of the normal variants without a discriminator follow the previous variant like in rustc,
+/// they are only known when the ranges are given discriminators
+fn check_overlaps(
+    data_enum: &DataEnum,
+    ranges: &VecDeque<(usize, Range, Attribute)>,
+    assign_discriminants: bool,
+) -> syn::Result<()> {
+    // The variant or range which owns each known discriminator value, along with the constant it is relative to
+    let mut owners: HashMap<(Option<String>, i128), String> = HashMap::new();
+    // The value of the next variant without a discriminator, unknown after a variant whose value isn't known
+    let mut next = Some((None, 0));
+
+    for (index, variant) in data_enum.variants.iter().enumerate() {
+        // the variant annotated with a checker_only range is removed and doesn't own values
+        if ranges
+            .iter()
+            .any(|(range_idx, range, _)| *range_idx == index && range.checker_only)
+        {
+            continue;
+        }
+
+        // the variant annotated with a range without variants is kept as a normal variant
+        let range = ranges
+            .iter()
+            .find(|(range_idx, _, _)| *range_idx == index)
+            .filter(|(_, range, _)| range.generates_variants());
+        let (name, values, span) = match range {
+            Some((_, range, attr)) => {
+                let base = range.base().map(|base| quote!(#base).to_string());
+                let values = range
+                    .values()
+                    .into_iter()
+                    .map(|value| (base.clone(), value))
+                    .collect::<Vec<_>>();
+                (
+                    format!("range `{}`", variant.ident),
+                    values,
+                    attr.to_token_stream(),
+                )
+            }
+            None => {
+                let value = match &variant.discriminant {
+                    Some((_, expr)) => literal_value(expr).map(|value| (None, value)),
+                    None if assign_discriminants => next.clone(),
+                    None => None,
+                };
+                let values = value.into_iter().collect::<Vec<_>>();
+                (
+                    format!("variant `{}`", variant.ident),
+                    values,
+                    variant.ident.to_token_stream(),
+                )
+            }
+        };
+
+        for value in values.iter() {
+            if let Some(owner) = owners.insert(value.clone(), name.clone()) {
+                return Err(syn::Error::new_spanned(
+                    span,
+                    format!(
+                        "{name} overlaps with {owner} on the value {}",
+                        describe_value(value)
+                    ),
+                ));
+            }
+        }
+
+        // A variant removed by its cfg doesn't take its values, so the value of the next one isn't known
+        next = match (cfg_attrs(variant).next(), values.last()) {
+            (Some(_), _) => None,
+            (None, Some((base, value))) => value.checked_add(1).map(|value| (base.clone(), value)),
+            // a range without values doesn't change the value of the next variant
+            (None, None) if range.is_some() => next,
+            (None, None) => None,
+        };
+    }
+
+    Ok(())
+}
+
+/// Writes a discriminator value, relative to a constant if it has one
+fn describe_value((base, value): &(Option<String>, i128)) -> String {
+    match base {
+        None => value.to_string(),
+        Some(base) if *value == 0 => base.clone(),
+        Some(base) if *value < 0 => format!("{base} - {}", -value),
+        Some(base) => format!("{base} + {value}"),
+    }
+}
+
+/// Gets the value of an integer literal discriminator, which can be negative
+fn literal_value(expr: &Expr) -> Option<i128> {
+    match expr {
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr,
+            ..
+        }) => literal_value(expr).map(|value| -value),
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(int), ..
+        }) => int.base10_parse().ok(),
+        Expr::Group(group) => literal_value(&group.expr),
+        Expr::Paren(paren) => literal_value(&paren.expr),
+        _ => None,
+    }
+}
+
+/// A range whose variants have been generated
+struct GeneratedRange {
+    range: Range,
+    /// The `#[range(...)]` attribute defining the range
+    attr: Attribute,
+    /// The annotated variant replaced by the range
+    variant: Variant,
+    /// The names of the generated variants, in the order they are generated
+    idents: Vec<Ident>,
+    /// The displayed names of the generated variants, in the order they are generated
+    displays: Vec<String>,
+}
+
+/// Generate a method for a range that checks if an enum variant is in it, its counterpart on raw values
+/// and a const array of all the variants generated for the range
+fn generate_range_checker(
+    data_enum: &DataEnum,
+    enum_ident: &Ident,
+    generics: &Generics,
+    repr: &Option<Ident>,
+    args: &EnumRangeArgs,
+    generated: &GeneratedRange,
+) -> syn::Result<Option<proc_macro2::TokenStream>> {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let GeneratedRange {
+        range,
+        attr,
+        variant,
+        idents: range_idents,
+        ..
+    } = generated;
+    let Some(range_check) = range.range_check.as_ref() else {
+        return Ok(None);
+    };
+    if repr.is_none() && (range.value_check == Some(true) || range.bounds_fn.is_some()) {
+        return Err(syn::Error::new_spanned(
+            attr,
+            "range value_check and bounds_fn require the enum to have a numerical #[repr(...)] attribute",
+        ));
+    }
+
+    let cfgs = cfg_attrs(variant).collect::<Vec<_>>();
+    let stem = range_check_stem(range_check);
+    let const_prefix = match &range.const_prefix {
+        // the prefix is checked along with a suffix, it doesn't need to be a valid identifier on its own
+        Some(const_prefix) => {
+            generated_name(
+                Some(&format!("{const_prefix}_MIN")),
+                "range const_prefix",
+                format_ident!("MIN"),
+            )
+            .map_err(|err| syn::Error::new_spanned(attr, err))?;
+            const_prefix.clone()
+        }
+        None => stem.to_uppercase(),
+    };
+    let variants_const = format_ident!("{}_VARIANTS", const_prefix);
+    let min_const = format_ident!("{}_MIN", const_prefix);
+    let max_const = format_ident!("{}_MAX", const_prefix);
+    let count_const = format_ident!("{}_COUNT", const_prefix);
+    let variants_count = range_idents.len();
+    let iter_name = generated_name(
+        range.iter_fn.as_deref(),
+        "range iter_fn",
+        format_ident!("{}_iter", stem),
+    )
+    .map_err(|err| syn::Error::new_spanned(attr, err))?;
+
+    let range_start = range.value_expr(range.min());
+    let range_end = range.value_expr(range.max());
+    let method_name = format_ident!("{}", range_check);
+
+    let skipped = range
+        .skipped_values()
+        .into_iter()
+        .map(|value| range.value_expr(value))
+        .collect::<Vec<_>>();
+    // The lowest and highest values of each interval of the range
+    let (window_starts, window_ends): (Vec<_>, Vec<_>) = range
+        .windows()
+        .into_iter()
+        .map(|(start, end)| {
+            (
+                range.value_expr(start.min(end)),
+                range.value_expr(start.max(end)),
+            )
+        })
+        .unzip();
+    let (lower_inclusive, upper_inclusive) = match range.range_check_bounds {
+        None | Some(RangeCheckBounds::Inclusive) => (true, true),
+        Some(RangeCheckBounds::Exclusive) => (false, false),
+        Some(RangeCheckBounds::InclusiveLower) => (true, false),
+        Some(RangeCheckBounds::InclusiveUpper) => (false, true),
+    };
+    // `matches!` rather than `RangeInclusive::contains` so that the check can be done in a `const fn`,
+    // constant expressions can't be used in patterns so they are compared instead
+    let contains = match range.base() {
+        None => {
+            // The excluded bounds are left out of the patterns, along with the intervals left empty
+            let (window_starts, window_ends): (Vec<_>, Vec<_>) = range
+                .windows()
+                .into_iter()
+                .map(|(start, end)| {
+                    (
+                        start.min(end) + i128::from(!lower_inclusive),
+                        start.max(end) - i128::from(!upper_inclusive),
+                    )
+                })
+                .filter(|(start, end)| start <= end)
+                .map(|(start, end)| (range.value_expr(start), range.value_expr(end)))
+                .unzip();
+            if window_starts.is_empty() {
+                quote!({
+                    let _ = value;
+                    false
+                })
+            } else if skipped.is_empty() {
+                quote!(::core::matches!(value, #(#window_starts..=#window_ends)|*))
+            } else {
+                quote! {
+                    ::core::matches!(value, #(#window_starts..=#window_ends)|*) && !::core::matches!(value, #(#skipped)|*)
+                }
+            }
+        }
+        Some(_) => {
+            let lower = if lower_inclusive {
+                quote!(<=)
+            } else {
+                quote!(<)
+            };
+            let upper = if upper_inclusive {
+                quote!(<=)
+            } else {
+                quote!(<)
+            };
+            quote! {
+                (#(#window_starts #lower value && value #upper #window_ends)||*) #(&& value != #skipped)*
+            }
+        }
+    };
+    let allow_comparisons = range
+        .base()
+        .map(|_| quote!(#[allow(clippy::manual_range_contains)]));
+    let constness = args.const_fns.then(|| quote!(const));
+    let vis = item_vis(range.vis.as_ref().or(args.vis.as_ref()));
+    // A trait method has the visibility of its trait and can't be const
+    let (method_vis, method_constness) = match range.range_trait {
+        Some(_) => (None, None),
+        None => (Some(vis.clone()), constness.clone()),
+    };
+
+    let value_check = repr
+        .as_ref()
+        .filter(|_| range.value_check.unwrap_or(true))
+        .map(|repr| {
+            let value_check_name = format_ident!("{}_value", range_check);
+            quote! {
+                #[inline]
+                #vis #constness fn #value_check_name(value: #repr) -> bool {
+                    #contains
+                }
+            }
+        });
+
+    let receiver = if range.by_value {
+        quote!(self)
+    } else {
+        quote!(&self)
+    };
+    let method = match repr {
+        Some(repr) if range.by_value && is_fieldless(data_enum) => quote! {
+            #[inline]
+            #method_vis #method_constness fn #method_name(self) -> bool {
+                let value = self as #repr;
+                #contains
+            }
+        },
+        Some(repr) => {
+            let value = discriminant_of_self(data_enum, repr)?;
+            quote! {
+                #[inline]
+                #method_vis #method_constness fn #method_name(#receiver) -> bool {
+                    let value: #repr = #value;
+                    #contains
+                }
+            }
+        }
+        // Without a repr the variants of the range are matched instead of their value
+        None => {
+            let patterns = range_idents.iter().map(|ident| {
+                variant_pattern(&Variant {
+                    ident: ident.clone(),
+                    ..(*variant).clone()
+                })
+            });
+            let contains = if range_idents.is_empty() {
+                quote!(false)
+            } else {
+                quote!(::core::matches!(self, #(#patterns)|*))
+            };
+            quote! {
+                #[inline]
+                #method_vis #method_constness fn #method_name(#receiver) -> bool {
+                    #contains
+                }
+            }
+        }
+    };
+
+    // The variants sorted by value, each one is the clamped variant up to the value of the next one
+    let mut sorted = if range.generates_variants() {
+        range
+            .values()
+            .into_iter()
+            .zip(range_idents)
+            .collect::<Vec<_>>()
+    } else {
+        Vec::new()
+    };
+    sorted.sort_by_key(|(value, _)| *value);
+    let clamp = repr
+        .as_ref()
+        .zip(sorted.split_last())
+        .map(|(repr, (highest, lower))| {
+            let clamp_name = format_ident!("clamp_to_{}", stem);
+            let highest = highest.1;
+            let (lower, next_values): (Vec<_>, Vec<_>) = lower
+                .iter()
+                .zip(&sorted[1..])
+                .map(|((_, ident), (next, _))| (*ident, range.value_expr(*next)))
+                .unzip();
+            quote! {
+                #vis #constness fn #clamp_name(value: #repr) -> Self {
+                    match value {
+                        #(value if value < #next_values => Self::#lower,)*
+                        _ => Self::#highest,
+                    }
+                }
+            }
+        });
+
+    // Variants with fields can't be listed without values for their fields
+    let variants =
+        (matches!(variant.fields, Fields::Unit) && range.generates_variants()).then(|| {
+            let next_name = format_ident!("{}_next", stem);
+            let prev_name = format_ident!("{}_prev", stem);
+            // Each variant of the range along with the one generated before it
+            let current = range_idents.get(1..).unwrap_or_default();
+            let previous = &range_idents[..range_idents.len().saturating_sub(1)];
+
+            quote! {
+                #vis const #variants_const: [Self; #variants_count] = [#(Self::#range_idents),*];
+
+                #vis fn #iter_name() -> impl ::core::iter::Iterator<Item = Self> {
+                    Self::#variants_const.into_iter()
+                }
+
+                #vis #constness fn #next_name(#receiver) -> ::core::option::Option<Self> {
+                    match self {
+                        #(Self::#previous => ::core::option::Option::Some(Self::#current),)*
+                        _ => ::core::option::Option::None,
+                    }
+                }
+
+                #vis #constness fn #prev_name(#receiver) -> ::core::option::Option<Self> {
+                    match self {
+                        #(Self::#current => ::core::option::Option::Some(Self::#previous),)*
+                        _ => ::core::option::Option::None,
+                    }
+                }
+
+                #clamp
+            }
+        });
+
+    let count = range
+        .generates_variants()
+        .then(|| quote!(#vis const #count_const: usize = #variants_count;));
+
+    let bounds_name = generated_name(
+        range.bounds_fn.as_deref(),
+        "range bounds_fn",
+        format_ident!("{}_bounds", stem),
+    )
+    .map_err(|err| syn::Error::new_spanned(attr, err))?;
+    let bounds = repr.as_ref().map(|repr| {
+        quote! {
+            #vis const #min_const: #repr = #range_start;
+
+            #vis const #max_const: #repr = #range_end;
+
+            #[inline]
+            #vis #constness fn #bounds_name() -> ::core::ops::RangeInclusive<#repr> {
+                Self::#min_const..=Self::#max_const
+            }
+        }
+    });
+
+    let (method, trait_impl) = match &range.range_trait {
+        Some(range_trait) => (
+            None,
+            Some(quote! {
+                #(#cfgs)*
+                #allow_comparisons
+                impl #impl_generics #range_trait for #enum_ident #ty_generics #where_clause {
+                    #method
+                }
+            }),
+        ),
+        None => (Some(method), None),
+    };
+
+    Ok(Some(quote! {
+        #trait_impl
+
+        #(#cfgs)*
+        #allow_comparisons
+        impl #impl_generics #enum_ident #ty_generics #where_clause {
+            #method
+
+            #value_check
+
+            #count
+
+            #bounds
+
+            #variants
+        }
+    }))
+}
+
+/// Name of the method generated by [generate_discriminant_fn]
+const DISCRIMINANT_FN: &str = "__enum_range_discriminant";
+
+/// Generate an expression getting the discriminator value of `self`, either by value or by reference
+/// It calls the method generated once per enum by [generate_discriminant_fn], so that the generated methods
+/// don't each match every variant of the enum
+fn discriminant_of_self(
+    data_enum: &DataEnum,
+    repr: &Ident,
+) -> syn::Result<proc_macro2::TokenStream> {
+    // the errors about the discriminators are reported on the items requiring them
+    discriminant_values(data_enum, repr)?;
+    let method_name = format_ident!("{}", DISCRIMINANT_FN);
+    Ok(quote!(self.#method_name()))
+}
+
+/// Generate the method of [generate_discriminant_fn] when the enum has a numerical repr
+/// The enums whose discriminators can't be computed don't get it, the items requiring it report the error
+fn generate_discriminant_fn_if_valid(
+    data_enum: &DataEnum,
+    enum_ident: &Ident,
+    generics: &Generics,
+    repr: &Option<Ident>,
+) -> Option<proc_macro2::TokenStream> {
+    let repr = repr.as_ref()?;
+    generate_discriminant_fn(data_enum, enum_ident, generics, repr).ok()
+}
+
+/// Generate the private method getting the discriminator value of a variant by reference
+/// This doesn't require the enum to be `Copy` as opposed to `*self as repr`, and doesn't read the tag with `unsafe`
+/// code, which the crates forbidding it couldn't use
+fn generate_discriminant_fn(
+    data_enum: &DataEnum,
+    enum_ident: &Ident,
+    generics: &Generics,
+    repr: &Ident,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let method_name = format_ident!("{}", DISCRIMINANT_FN);
+    let discriminants = discriminant_values(data_enum, repr)?;
+    let arms = data_enum
+        .variants
+        .iter()
+        .zip(discriminants)
+        .map(|(variant, discriminant)| {
+            let cfgs = cfg_attrs(variant);
+            let pattern = variant_pattern(variant);
+            quote! {
+                #(#cfgs)*
+                #pattern => #discriminant,
+            }
+        });
+
+    Ok(quote! {
+        impl #impl_generics #enum_ident #ty_generics #where_clause {
+            #[doc(hidden)]
+            #[inline]
+            #[allow(dead_code)]
+            const fn #method_name(&self) -> #repr {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+    })
+}
+
+/// Generate an expression of the discriminator value of each variant
+/// Only the variants of an enum without fields can be cast to its repr, otherwise the values are computed here
+/// from the integer literal discriminators, a variant without one following the previous variant
+fn discriminant_values(
+    data_enum: &DataEnum,
+    repr: &Ident,
+) -> syn::Result<Vec<proc_macro2::TokenStream>> {
+    let fieldless = is_fieldless(data_enum);
+    let mut next = Some(0);
+
+    data_enum
+        .variants
+        .iter()
+        .map(|variant| {
+            let ident = &variant.ident;
+            let value = match &variant.discriminant {
+                Some((_, expr)) => literal_value(expr),
+                None => next,
+            };
+            // A variant removed by its cfg doesn't take its value, so the value of the next one isn't known
+            next = match cfg_attrs(variant).next() {
+                Some(_) => None,
+                None => value.and_then(|value| value.checked_add(1)),
+            };
+
+            if fieldless {
+                Ok(quote!(Self::#ident as #repr))
+            } else if let Some(value) = value {
+                let literal = Literal::i128_unsuffixed(value);
+                Ok(quote!(#literal))
+            } else {
+                Err(syn::Error::new_spanned(
+                    variant,
+                    "enum_range can't compute the discriminator value of this variant, give it an integer literal discriminator",
+                ))
+            }
+        })
+        .collect()
+}
+
+/// Checks if no variant of the enum has fields
+fn is_fieldless(data_enum: &DataEnum) -> bool {
+    data_enum
+        .variants
+        .iter()
+        .all(|variant| matches!(variant.fields, Fields::Unit))
+}
+
+/// Generate a pattern matching a variant whatever its fields are
+fn variant_pattern(variant: &Variant) -> proc_macro2::TokenStream {
+    let ident = &variant.ident;
+    match &variant.fields {
+        Fields::Named(_) => quote!(Self::#ident { .. }),
+        Fields::Unnamed(_) => quote!(Self::#ident(..)),
+        Fields::Unit => quote!(Self::#ident),
+    }
+}
+
+/// Gets the name of a range from its `range_check` method name, without the `is_` prefix
+fn range_check_stem(range_check: &str) -> &str {
+    range_check.strip_prefix("is_").unwrap_or(range_check)
+}
+
+/// Generate the methods telling which range generated a variant: `is_in_any_range` if the enum has a numerical repr,
+/// `which_range` returning the label of the range and `index_of` returning the position of the variant in it
+fn generate_range_lookups(
+    enum_ident: &Ident,
+    generics: &Generics,
+    repr: &Option<Ident>,
+    args: &EnumRangeArgs,
+    generated_ranges: &[GeneratedRange],
+) -> syn::Result<Option<proc_macro2::TokenStream>> {
+    if generated_ranges.is_empty() {
+        return Ok(None);
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let constness = args.const_fns.then(|| quote!(const));
+    let vis = item_vis(args.vis.as_ref());
+    let which_range_name = generated_name(
+        args.which_range_fn.as_deref(),
+        "which_range_fn",
+        format_ident!("which_range"),
+    )?;
+    let index_of_name = generated_name(
+        args.index_of_fn.as_deref(),
+        "index_of_fn",
+        format_ident!("index_of"),
+    )?;
+    let is_in_any_range_name = generated_name(
+        args.is_in_any_range_fn.as_deref(),
+        "is_in_any_range_fn",
+        format_ident!("is_in_any_range"),
+    )?;
+
+    // The cfgs and the patterns matching the variants of each range, along with the label of the range
+    let ranges = generated_ranges
+        .iter()
+        .filter(|generated| !generated.idents.is_empty())
+        .map(|generated| {
+            let cfgs = cfg_attrs(&generated.variant).collect::<Vec<_>>();
+            let patterns = generated.idents.iter().map(|ident| {
+                variant_pattern(&Variant {
+                    ident: ident.clone(),
+                    ..generated.variant.clone()
+                })
+            });
+            let label = generated.range.label(&generated.variant);
+            (quote!(#(#cfgs)* #(#patterns)|*), label)
+        })
+        .collect::<Vec<_>>();
+
+    let any_arms = ranges.iter().map(|(pattern, _)| quote!(#pattern => true,));
+    let is_in_any_range = repr.is_some().then(|| {
+        quote! {
+            #vis #constness fn #is_in_any_range_name(&self) -> bool {
+                match self {
+                    #(#any_arms)*
+                    // unreachable when every variant comes from a range
+                    #[allow(unreachable_patterns)]
+                    _ => false,
+                }
+            }
+        }
+    });
+
+    let which_arms = ranges
+        .iter()
+        .map(|(pattern, label)| quote!(#pattern => ::core::option::Option::Some(#label),));
+
+    // The position of every variant in its range is known, so the stepped and skipped values never come up
+    let index_arms = generated_ranges.iter().flat_map(|generated| {
+        let cfgs = cfg_attrs(&generated.variant).collect::<Vec<_>>();
+        generated
+            .idents
+            .iter()
+            .enumerate()
+            .map(move |(index, ident)| {
+                let pattern = variant_pattern(&Variant {
+                    ident: ident.clone(),
+                    ..generated.variant.clone()
+                });
+                quote! {
+                    #(#cfgs)*
+                    #pattern => ::core::option::Option::Some(#index),
+                }
+            })
+    });
+
+    Ok(Some(quote! {
+        impl #impl_generics #enum_ident #ty_generics #where_clause {
+            #is_in_any_range
+
+            #vis #constness fn #which_range_name(&self) -> ::core::option::Option<&'static str> {
+                match self {
+                    #(#which_arms)*
+                    #[allow(unreachable_patterns)]
+                    _ => ::core::option::Option::None,
+                }
+            }
+
+            #vis #constness fn #index_of_name(&self) -> ::core::option::Option<usize> {
+                match self {
+                    #(#index_arms)*
+                    #[allow(unreachable_patterns)]
+                    _ => ::core::option::Option::None,
+                }
+            }
+        }
+    }))
+}
+
+/// Generate the accessor returning the discriminator value of a variant, if the enum has a numerical repr
+fn generate_value_fn(
+    data_enum: &DataEnum,
+    enum_ident: &Ident,
+    generics: &Generics,
+    repr: &Option<Ident>,
+    args: &EnumRangeArgs,
+) -> syn::Result<Option<proc_macro2::TokenStream>> {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let Some(repr) = repr.as_ref() else {
+        return Ok(None);
+    };
+    let method_name = generated_name(args.value_fn.as_deref(), "value_fn", format_ident!("value"))?;
+    let constness = args.const_fns.then(|| quote!(const));
+    let vis = item_vis(args.vis.as_ref());
+    let value = if is_fieldless(data_enum) {
+        quote!(self as #repr)
+    } else {
+        discriminant_of_self(data_enum, repr)?
+    };
+
+    Ok(Some(quote! {
+        impl #impl_generics #enum_ident #ty_generics #where_clause {
+            #[inline]
+            #vis #constness fn #method_name(self) -> #repr {
+                #value
+            }
+        }
+    }))
+}
+
+/// Generate the `VARIANT_NAMES` const, or the const named by `names_table_name`, listing the name and the discriminator value of every variant
+fn generate_names_table(
+    data_enum: &DataEnum,
+    enum_ident: &Ident,
+    generics: &Generics,
+    repr: &Option<Ident>,
+    args: &EnumRangeArgs,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let repr = require_repr(repr, "names_table")?;
+    let vis = item_vis(args.vis.as_ref());
+    let names_ident = generated_name(
+        args.names_table_name.as_deref(),
+        "names_table_name",
+        format_ident!("VARIANT_NAMES"),
+    )?;
+    let discriminants = discriminant_values(data_enum, repr)?;
+    let entries = data_enum
+        .variants
+        .iter()
+        .zip(discriminants)
+        .map(|(variant, discriminant)| {
+            let cfgs = cfg_attrs(variant);
+            let name = variant.ident.unraw().to_string();
+            quote! {
+                #(#cfgs)*
+                (#name, #discriminant)
+            }
+        });
+
+    Ok(quote! {
+        impl #impl_generics #enum_ident #ty_generics #where_clause {
+            #vis const #names_ident: &'static [(&'static str, #repr)] = &[#(#entries),*];
+        }
+    })
+}
+
+/// Generate the const array mapping each value from the lowest discriminator to the highest one to its variant
+fn generate_discriminant_table(
+    data_enum: &DataEnum,
+    enum_ident: &Ident,
+    generics: &Generics,
+    repr: &Option<Ident>,
+    args: &EnumRangeArgs,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let repr = require_repr(repr, "discriminant_table")?;
+    let vis = item_vis(args.vis.as_ref());
+    let values = known_discriminants(data_enum, "discriminant_table")?;
+
+    let min = values.iter().map(|(value, _)| *value).min().unwrap_or(0);
+    let max = values.iter().map(|(value, _)| *value).max().unwrap_or(0);
+    // The span between the extremes of an i128 repr only fits in an u128, and 2^128 entries don't at all
+    let span = match values.is_empty() {
+        true => Some(0),
+        false => max.abs_diff(min).checked_add(1),
+    };
+    let max_variants = args.max_variants.unwrap_or(DEFAULT_MAX_VARIANTS);
+    let Some(span) = span.filter(|span| *span <= max_variants as u128) else {
+        let entries = span.map_or_else(|| "2^128".to_string(), |span| span.to_string());
+        return Err(syn::Error::new(
+            Span::call_site(),
+            format!("discriminant_table would have {entries} entries, more than the limit of {max_variants} set by the max_variants option"),
+        ));
+    };
+
+    let mut table = vec![quote!(::core::option::Option::None); span as usize];
+    for (value, ident) in values {
+        table[(value - min) as usize] = quote!(::core::option::Option::Some(Self::#ident));
+    }
+    let span = span as usize;
+    let min = Literal::i128_unsuffixed(min);
+
+    Ok(quote! {
+        impl #impl_generics #enum_ident #ty_generics #where_clause {
+            #vis const DISCRIMINANT_TABLE_MIN: #repr = #min;
+
+            #vis const DISCRIMINANT_TABLE: [::core::option::Option<Self>; #span] = [#(#table),*];
+        }
+    })
+}
+
+/// Generate the `Display` implementation writing the name of the variants
+/// The variants generated from a range with a `display` template are written with it instead
+fn generate_display(
+    data_enum: &DataEnum,
+    enum_ident: &Ident,
+    generics: &Generics,
+    generated_ranges: &[GeneratedRange],
+) -> proc_macro2::TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let range_displays: HashMap<&Ident, &String> = generated_ranges
+        .iter()
+        .flat_map(|generated| generated.idents.iter().zip(generated.displays.iter()))
+        .collect();
+
+    let arms = data_enum.variants.iter().map(|variant| {
+        let cfgs = cfg_attrs(variant);
+        let ident = &variant.ident;
+        let pattern = variant_pattern(variant);
+        let display = range_displays
+            .get(ident)
+            .map(|display| display.to_string())
+            .unwrap_or_else(|| ident.unraw().to_string());
+        quote! {
+            #(#cfgs)*
+            #pattern => #display,
+        }
+    });
+
+    quote! {
+        impl #impl_generics ::core::fmt::Display for #enum_ident #ty_generics #where_clause {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.pad(match self {
+                    #(#arms)*
+                })
+            }
+        }
+    }
+}
+
+/// Generate the `Debug` implementation writing the name of the variants along with their discriminator value
+fn generate_debug(
+    data_enum: &DataEnum,
+    enum_ident: &Ident,
+    generics: &Generics,
+    repr: &Option<Ident>,
+    args: &EnumRangeArgs,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let repr = require_repr(repr, "debug_with_value")?;
+    let discriminants = discriminant_values(data_enum, repr)?;
+    let format = match args.value_radix.unwrap_or(Radix::Dec) {
+        Radix::Dec => "{}({})",
+        Radix::Hex => "{}({:#X})",
+        Radix::Bin => "{}({:#b})",
+    };
+
+    let arms = data_enum
+        .variants
+        .iter()
+        .zip(discriminants)
+        .map(|(variant, discriminant)| {
+            let cfgs = cfg_attrs(variant);
+            let pattern = variant_pattern(variant);
+            let name = variant.ident.unraw().to_string();
+            quote! {
+                #(#cfgs)*
+                #pattern => {
+                    let value: #repr = #discriminant;
+                    ::core::write!(f, #format, #name, value)
+                }
+            }
+        });
+
+    Ok(quote! {
+        impl #impl_generics ::core::fmt::Debug for #enum_ident #ty_generics #where_clause {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+    })
+}
+
+/// Generate the `from_repr` constructor mapping every discriminator value back to its variant
+fn generate_from_repr(
+    data_enum: &DataEnum,
+    enum_ident: &Ident,
+    generics: &Generics,
+    repr: &Option<Ident>,
+    args: &EnumRangeArgs,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let repr = require_repr(repr, "from_repr")?;
+    let constness = args.const_fns.then(|| quote!(const));
+    let vis = item_vis(args.vis.as_ref());
+    let arms = repr_match_arms(
+        data_enum,
+        repr,
+        |variant| quote!(::core::option::Option::Some(#variant)),
+    )?;
+
+    Ok(quote! {
+        impl #impl_generics #enum_ident #ty_generics #where_clause {
+            #vis #constness fn from_repr(value: #repr) -> ::core::option::Option<Self> {
+                match value {
+                    #(#arms)*
+                    _ => ::core::option::Option::None,
+                }
+            }
+        }
+    })
+}
+
+/// Generate the `saturating_from_repr` constructor mapping every value to the variant with the nearest discriminator
+/// below it, or to the lowest variant
+fn generate_saturating_from_repr(
+    data_enum: &DataEnum,
+    enum_ident: &Ident,
+    generics: &Generics,
+    repr: &Option<Ident>,
+    args: &EnumRangeArgs,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let repr = require_repr(repr, "saturating_from_repr")?;
+    let constness = args.const_fns.then(|| quote!(const));
+    let vis = item_vis(args.vis.as_ref());
+
+    let mut sorted = known_discriminants(data_enum, "saturating_from_repr")?;
+    sorted.sort_by_key(|(value, _)| *value);
+    let Some(((_, highest), lower)) = sorted.split_last() else {
+        return Err(syn::Error::new(
+            Span::call_site(),
+            "saturating_from_repr requires the enum to have variants",
+        ));
+    };
+    // Each variant is the result up to the discriminator of the next one
+    let (lower, next_values): (Vec<_>, Vec<_>) = lower
+        .iter()
+        .zip(&sorted[1..])
+        .map(|((_, ident), (next, _))| (*ident, Literal::i128_unsuffixed(*next)))
+        .unzip();
+
+    Ok(quote! {
+        impl #impl_generics #enum_ident #ty_generics #where_clause {
+            #vis #constness fn saturating_from_repr(value: #repr) -> Self {
+                match value {
+                    #(value if value < #next_values => Self::#lower,)*
+                    _ => Self::#highest,
+                }
+            }
+        }
+    })
+}
+
+/// Collects the discriminator value of each variant for the `option` building its variants from their value,
+/// which requires variants without fields nor a cfg, and discriminators known by the macro
+fn known_discriminants<'a>(
+    data_enum: &'a DataEnum,
+    option: &str,
+) -> syn::Result<Vec<(i128, &'a Ident)>> {
+    let mut next = Some(0);
+    let mut values = Vec::new();
+    for variant in data_enum.variants.iter() {
+        let error = |message: &str| syn::Error::new_spanned(variant, format!("{option} {message}"));
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(error("can't build the variants with fields"));
+        }
+        if cfg_attrs(variant).next().is_some() {
+            return Err(error(
+                "can't hold the variants with a cfg, the generated code would change with it",
+            ));
+        }
+        let value = match &variant.discriminant {
+            Some((_, expr)) => literal_value(expr),
+            None => next,
+        }
+        .ok_or_else(|| error("can't compute the discriminator value of this variant, give it an integer literal discriminator"))?;
+        next = value.checked_add(1);
+        values.push((value, &variant.ident));
+    }
+
+    Ok(values)
+}
+
+/// Generate the `TryFrom<repr>` implementation and its error type
+fn generate_try_from(
+    data_enum: &DataEnum,
+    enum_ident: &Ident,
+    generics: &Generics,
+    vis: &Visibility,
+    repr: &Option<Ident>,
+    args: &EnumRangeArgs,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let repr = require_repr(repr, "try_from")?;
+    let arms = repr_match_arms(
+        data_enum,
+        repr,
+        |variant| quote!(::core::result::Result::Ok(#variant)),
+    )?;
+
+    let error_ident = generated_name(
+        args.try_from_error_name.as_deref(),
+        "try_from_error_name",
+        format_ident!("{}TryFromError", enum_ident),
+    )?;
+    let error_doc = format!(
+        "Error returned when converting a value which is not the discriminator of a [{enum_ident}] variant"
+    );
+    let error_impl = generate_error_impl(&error_ident);
+
+    Ok(quote! {
+        #[doc = #error_doc]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #vis struct #error_ident(pub #repr);
+
+        impl ::core::fmt::Display for #error_ident {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::write!(f, "invalid {} discriminator: {}", ::core::stringify!(#enum_ident), self.0)
+            }
+        }
+
+        #error_impl
+
+        impl #impl_generics ::core::convert::TryFrom<#repr> for #enum_ident #ty_generics #where_clause {
+            type Error = #error_ident;
+
+            fn try_from(value: #repr) -> ::core::result::Result<Self, Self::Error> {
+                match value {
+                    #(#arms)*
+                    _ => ::core::result::Result::Err(#error_ident(value)),
+                }
+            }
+        }
+    })
+}
+
+/// Generate the `PartialEq` implementations comparing the variants to the values of the repr, in both directions
+fn generate_eq_repr(
+    data_enum: &DataEnum,
+    enum_ident: &Ident,
+    generics: &Generics,
+    repr: &Option<Ident>,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let repr = require_repr(repr, "eq_repr")?;
+    let value = discriminant_of_self(data_enum, repr)?;
+
+    Ok(quote! {
+        impl #impl_generics ::core::cmp::PartialEq<#repr> for #enum_ident #ty_generics #where_clause {
+            fn eq(&self, other: &#repr) -> bool {
+                let value: #repr = #value;
+                value == *other
+            }
+        }
+
+        impl #impl_generics ::core::cmp::PartialEq<#enum_ident #ty_generics> for #repr #where_clause {
+            fn eq(&self, other: &#enum_ident #ty_generics) -> bool {
+                other == self
+            }
+        }
+    })
+}
+
+/// Generate the `From` implementation converting the variants to their discriminator value
+fn generate_into_repr(
+    data_enum: &DataEnum,
+    enum_ident: &Ident,
+    generics: &Generics,
+    repr: &Option<Ident>,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let repr = require_repr(repr, "into_repr")?;
+
+    // `Self` is the repr in this impl, the variants with fields are matched through the path of the enum
+    let value = if is_fieldless(data_enum) {
+        quote!(value as #repr)
+    } else {
+        let turbofish = ty_generics.as_turbofish();
+        let discriminants = discriminant_values(data_enum, repr)?;
+        let arms = data_enum
+            .variants
+            .iter()
+            .zip(discriminants)
+            .map(|(variant, discriminant)| {
+                let cfgs = cfg_attrs(variant);
+                let ident = &variant.ident;
+                let pattern = match &variant.fields {
+                    Fields::Named(_) => quote!(#enum_ident #turbofish::#ident { .. }),
+                    Fields::Unnamed(_) => quote!(#enum_ident #turbofish::#ident(..)),
+                    Fields::Unit => quote!(#enum_ident #turbofish::#ident),
+                };
+                quote! {
+                    #(#cfgs)*
+                    #pattern => #discriminant,
+                }
+            });
+        quote! {
+            match value {
+                #(#arms)*
+            }
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::core::convert::From<#enum_ident #ty_generics> for #repr #where_clause {
+            fn from(value: #enum_ident #ty_generics) -> Self {
+                #value
+            }
+        }
+    })
+}
+
+/// Generate the enum of the ranges generating variants, named after their annotated variant,
+/// along with the `range` method giving the range of a variant
+fn generate_range_enum(
+    enum_ident: &Ident,
+    generics: &Generics,
+    vis: &Visibility,
+    args: &EnumRangeArgs,
+    generated_ranges: &[GeneratedRange],
+) -> syn::Result<proc_macro2::TokenStream> {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let method_vis = item_vis(args.vis.as_ref());
+    let constness = args.const_fns.then(|| quote!(const));
+    let range_enum_ident = generated_name(
+        args.range_enum_name.as_deref(),
+        "range_enum_name",
+        format_ident!("{}Range", enum_ident),
+    )?;
+    let range_enum_doc = format!("The ranges generating the variants of [{enum_ident}]");
+
+    let ranges = generated_ranges
+        .iter()
+        .filter(|generated| !generated.idents.is_empty())
+        .collect::<Vec<_>>();
+    let range_variants = ranges.iter().map(|generated| {
+        let cfgs = cfg_attrs(&generated.variant);
+        let ident = &generated.variant.ident;
+        quote! {
+            #(#cfgs)*
+            #ident,
+        }
+    });
+    let arms = ranges.iter().map(|generated| {
+        let cfgs = cfg_attrs(&generated.variant).collect::<Vec<_>>();
+        let ident = &generated.variant.ident;
+        let patterns = generated.idents.iter().map(|range_ident| {
+            variant_pattern(&Variant {
+                ident: range_ident.clone(),
+                ..generated.variant.clone()
+            })
+        });
+        quote! {
+            #(#cfgs)*
+            #(#patterns)|* => ::core::option::Option::Some(#range_enum_ident::#ident),
+        }
+    });
+
+    Ok(quote! {
+        #[doc = #range_enum_doc]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #vis enum #range_enum_ident {
+            #(#range_variants)*
+        }
+
+        impl #impl_generics #enum_ident #ty_generics #where_clause {
+            #method_vis #constness fn range(&self) -> ::core::option::Option<#range_enum_ident> {
+                match self {
+                    #(#arms)*
+                    #[allow(unreachable_patterns)]
+                    _ => ::core::option::Option::None,
+                }
+            }
+        }
+    })
+}
+
+/// Generate the `matches_category` method checking a variant against the labels of the ranges and the names of the
+/// categories, along with the `CATEGORY_NAMES` const listing them
+fn generate_category_matches(
+    data_enum: &DataEnum,
+    enum_ident: &Ident,
+    generics: &Generics,
+    repr: &Option<Ident>,
+    args: &EnumRangeArgs,
+    generated_ranges: &[GeneratedRange],
+) -> syn::Result<Option<proc_macro2::TokenStream>> {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let vis = item_vis(args.vis.as_ref());
+    let method_name = generated_name(
+        args.matches_category_fn.as_deref(),
+        "matches_category_fn",
+        format_ident!("matches_category"),
+    )?;
+    let names_const = generated_name(
+        args.category_names_const.as_deref(),
+        "category_names_const",
+        format_ident!("CATEGORY_NAMES"),
+    )?;
+
+    // Each label along with the cfgs of its range and the check of a variant
+    let mut checks = Vec::new();
+    for generated in generated_ranges {
+        let cfgs = cfg_attrs(&generated.variant).cloned().collect::<Vec<_>>();
+        let range = &generated.range;
+        let check = match (&range.range_check, repr) {
+            // the method taking `self` can't be called on a borrowed variant which isn't `Copy`, its value is checked
+            (Some(range_check), Some(repr))
+                if range.by_value && range.value_check.unwrap_or(true) =>
+            {
+                let value_check_name = format_ident!("{}_value", range_check);
+                let value = discriminant_of_self(data_enum, repr)?;
+                quote!(Self::#value_check_name({
+                    let value: #repr = #value;
+                    value
+                }))
+            }
+            (Some(range_check), _) if !range.by_value => {
+                let method_name = format_ident!("{}", range_check);
+                match &range.range_trait {
+                    Some(range_trait) => quote!(<Self as #range_trait>::#method_name(self)),
+                    None => quote!(self.#method_name()),
+                }
+            }
+            _ => {
+                let patterns = generated.idents.iter().map(|ident| {
+                    variant_pattern(&Variant {
+                        ident: ident.clone(),
+                        ..generated.variant.clone()
+                    })
+                });
+                if generated.idents.is_empty() {
+                    quote!(false)
+                } else {
+                    quote!(::core::matches!(self, #(#patterns)|*))
+                }
+            }
+        };
+        checks.push((generated.range.label(&generated.variant), cfgs, check));
+    }
+    for category in args.category.iter() {
+        let method_name = format_ident!("is_{}", category.name);
+        checks.push((
+            category.name.clone(),
+            Vec::new(),
+            quote!(self.#method_name()),
+        ));
+    }
+
+    if checks.is_empty() {
+        return Ok(None);
+    }
+
+    // A label shared by several ranges is only listed once, without the cfgs which only apply to some of them
+    let mut names = Vec::<(&String, &[Attribute])>::new();
+    for (label, cfgs, _) in checks.iter() {
+        match names.iter_mut().find(|(name, _)| *name == label) {
+            Some(name) => name.1 = &[],
+            None => names.push((label, cfgs)),
+        }
+    }
+    let names = names.into_iter().map(|(name, cfgs)| {
+        quote! {
+            #(#cfgs)*
+            #name
+        }
+    });
+    let checks = checks.iter().map(|(label, cfgs, check)| {
+        quote! {
+            #(#cfgs)*
+            if name == #label && #check {
+                return true;
+            }
+        }
+    });
+
+    Ok(Some(quote! {
+        impl #impl_generics #enum_ident #ty_generics #where_clause {
+            #vis const #names_const: &'static [&'static str] = &[#(#names),*];
+
+            #vis fn #method_name(&self, name: &str) -> bool {
+                #(#checks)*
+                false
+            }
+        }
+    }))
+}
+
+/// Generate the method checking if a variant is in a category and its counterpart on raw values
+fn generate_category(
+    data_enum: &DataEnum,
+    enum_ident: &Ident,
+    generics: &Generics,
+    repr: &Option<Ident>,
+    args: &EnumRangeArgs,
+    category: &Category,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let repr = require_repr(repr, "category")?;
+    let Category { name, start, end } = category;
+    let error = |message: String| syn::Error::new(Span::call_site(), message);
+    if !same_base(&start.base, &end.base) || start.is_char != end.is_char {
+        return Err(error(format!(
+            "category `{name}` start and end must be of the same kind and relative to the same constant"
+        )));
+    }
+    if start.value > end.value {
+        return Err(error(format!(
+            "category `{name}` start ({}) is greater than its end ({})",
+            start.value, end.value
+        )));
+    }
+    if let (None, Some((min, max))) = (&start.base, repr_bounds(repr)) {
+        if start.value < min || end.value > max {
+            return Err(error(format!(
+                "category `{name}` doesn't fit in the enum repr `{repr}` ({min}..={max})"
+            )));
+        }
+    }
+
+    // The bounds are written like the ones of a range
+    let range = Range {
+        start: Some(start.clone()),
+        end: Some(end.clone()),
+        ..Default::default()
+    };
+    let (min, max) = (range.value_expr(start.value), range.value_expr(end.value));
+    let contains = match range.base() {
+        None => quote!(::core::matches!(value, #min..=#max)),
+        Some(_) => quote!(#min <= value && value <= #max),
+    };
+    let allow_comparisons = range
+        .base()
+        .map(|_| quote!(#[allow(clippy::manual_range_contains)]));
+    // Like the ones of a range, the values relative to a constant are asserted to fit in the repr
+    let repr_assert = range.base().map(|base| {
+        let fits = fits_in_repr(repr, &base, [start.value, end.value]);
+        let message = format!("category `{name}` doesn't fit in the enum repr `{repr}`");
+        quote!(
+            const _: () = ::core::assert!(#fits, #message);
+        )
+    });
+
+    let method_name = format_ident!("is_{}", name);
+    let value_check_name = format_ident!("is_{}_value", name);
+    let constness = args.const_fns.then(|| quote!(const));
+    let vis = item_vis(args.vis.as_ref());
+    let value = discriminant_of_self(data_enum, repr)?;
+
+    Ok(quote! {
+        #repr_assert
+
+        #allow_comparisons
+        impl #impl_generics #enum_ident #ty_generics #where_clause {
+            #[inline]
+            #vis #constness fn #method_name(&self) -> bool {
+                let value: #repr = #value;
+                #contains
+            }
+
+            #[inline]
+            #vis #constness fn #value_check_name(value: #repr) -> bool {
+                #contains
+            }
+        }
+    })
+}
+
+/// Generate the module holding a const alias of every variant without fields
+fn generate_wrap_module(
+    data_enum: &DataEnum,
+    enum_ident: &Ident,
+    generics: &Generics,
+    vis: &Visibility,
+    module: &str,
+) -> syn::Result<proc_macro2::TokenStream> {
+    if !generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            generics,
+            "wrap_module requires an enum without generics, consts can't be generic",
+        ));
+    }
+    let module = syn::parse_str::<Ident>(module).map_err(|_| {
+        syn::Error::new(
+            Span::call_site(),
+            format!("wrap_module `{module}` is not a valid module name"),
+        )
+    })?;
+    // The consts are seen from the module of the enum like the enum itself,
+    // the visibilities relative to it are one level deeper in the generated module
+    let const_vis = match vis {
+        Visibility::Inherited => quote!(pub(super)),
+        Visibility::Restricted(restricted) if restricted.path.is_ident("self") => {
+            quote!(pub(super))
+        }
+        Visibility::Restricted(restricted)
+            if restricted
+                .path
+                .segments
+                .first()
+                .is_some_and(|segment| segment.ident == "super") =>
+        {
+            let path = &restricted.path;
+            quote!(pub(in super::#path))
+        }
+        vis => quote!(#vis),
+    };
+    let aliases = data_enum
+        .variants
+        .iter()
+        .filter(|variant| matches!(variant.fields, Fields::Unit))
+        .map(|variant| {
+            let cfgs = cfg_attrs(variant);
+            let ident = &variant.ident;
+            quote! {
+                #(#cfgs)*
+                #const_vis const #ident: super::#enum_ident = super::#enum_ident::#ident;
+            }
+        });
+
+    Ok(quote! {
+        #[allow(non_upper_case_globals)]
+        #vis mod #module {
+            #(#aliases)*
+        }
+    })
+}
+
+/// Generate the methods converting the variants of the range with `as_bit` to and from bit masks
+fn generate_bits(
+    enum_ident: &Ident,
+    generics: &Generics,
+    repr: &Option<Ident>,
+    args: &EnumRangeArgs,
+    generated_ranges: &[GeneratedRange],
+) -> syn::Result<Option<proc_macro2::TokenStream>> {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let mut bit_ranges = generated_ranges
+        .iter()
+        .filter(|generated| generated.range.as_bit);
+    let Some(generated) = bit_ranges.next() else {
+        return Ok(None);
+    };
+    if let Some(other) = bit_ranges.next() {
+        return Err(syn::Error::new_spanned(
+            &other.attr,
+            format!(
+                "range as_bit is already given by the range `{}`, only one range can be bits",
+                generated.variant.ident
+            ),
+        ));
+    }
+    let GeneratedRange {
+        range,
+        attr,
+        variant,
+        idents,
+        ..
+    } = generated;
+    let Some(repr) = repr else {
+        return Err(syn::Error::new_spanned(
+            attr,
+            "range as_bit requires the enum to have a numerical #[repr(...)] attribute",
+        ));
+    };
+    let first = range.first_bound();
+    if first.base.is_some() || first.is_char {
+        return Err(syn::Error::new_spanned(
+            attr,
+            "range as_bit requires integer bounds",
+        ));
+    }
+    // The widest targets give the bit width of `usize` and `isize`
+    let bits = repr
+        .to_string()
+        .trim_start_matches(['u', 'i'])
+        .parse::<i128>()
+        .unwrap_or(64);
+    if range.min() < 0 || range.max() >= bits {
+        return Err(syn::Error::new_spanned(
+            attr,
+            format!(
+                "range as_bit positions must be between 0 and {} for the enum repr `{repr}`",
+                bits - 1
+            ),
+        ));
+    }
+
+    let cfgs = cfg_attrs(variant).collect::<Vec<_>>();
+    let patterns = idents
+        .iter()
+        .map(|ident| {
+            variant_pattern(&Variant {
+                ident: ident.clone(),
+                ..variant.clone()
+            })
+        })
+        .collect::<Vec<_>>();
+    let positions = range
+        .values()
+        .into_iter()
+        .map(Literal::i128_unsuffixed)
+        .collect::<Vec<_>>();
+    let constness = args.const_fns.then(|| quote!(const));
+    let vis = item_vis(range.vis.as_ref().or(args.vis.as_ref()));
+
+    // Variants with fields can't be built from a mask
+    let from_mask = matches!(variant.fields, Fields::Unit).then(|| {
+        quote! {
+            #vis #constness fn from_mask(mask: #repr) -> ::core::option::Option<Self> {
+                match mask {
+                    #(mask if mask == 1 << #positions => ::core::option::Option::Some(Self::#idents),)*
+                    _ => ::core::option::Option::None,
+                }
+            }
+        }
+    });
+
+    Ok(Some(quote! {
+        #(#cfgs)*
+        impl #impl_generics #enum_ident #ty_generics #where_clause {
+            #[inline]
+            #vis #constness fn bit_mask(&self) -> #repr {
+                match self {
+                    #(#patterns => 1 << #positions,)*
+                    // unreachable when every variant comes from the range
+                    #[allow(unreachable_patterns)]
+                    _ => 0,
+                }
+            }
+
+            #from_mask
+        }
+    }))
+}
+
+/// Generate the `Default` implementation returning a variant of the range marked as `default`
+fn generate_default(
+    enum_ident: &Ident,
+    generics: &Generics,
+    generated_ranges: &[GeneratedRange],
+) -> syn::Result<Option<proc_macro2::TokenStream>> {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let mut defaults = generated_ranges
+        .iter()
+        .filter(|generated| generated.range.default || generated.range.default_value.is_some());
+    let Some(generated) = defaults.next() else {
+        return Ok(None);
+    };
+    if let Some(other) = defaults.next() {
+        return Err(syn::Error::new_spanned(
+            &other.attr,
+            format!(
+                "range default is already given by the range `{}`, only one range can be the default",
+                generated.variant.ident
+            ),
+        ));
+    }
+    if !matches!(generated.variant.fields, Fields::Unit) {
+        return Err(syn::Error::new_spanned(
+            &generated.attr,
+            "range default requires variants without fields",
+        ));
+    }
+
+    let index = match &generated.range.default_value {
+        Some(default_value) => generated
+            .range
+            .values()
+            .iter()
+            .position(|value| *value == default_value.value)
+            .unwrap_or_default(),
+        None => 0,
+    };
+    let Some(ident) = generated.idents.get(index) else {
+        return Err(syn::Error::new_spanned(
+            &generated.attr,
+            "range default requires the range to have at least one variant",
+        ));
+    };
+    let cfgs = cfg_attrs(&generated.variant);
+
+    Ok(Some(quote! {
+        #(#cfgs)*
+        impl #impl_generics ::core::default::Default for #enum_ident #ty_generics #where_clause {
+            fn default() -> Self {
+                Self::#ident
+            }
+        }
+    }))
+}
+
+/// Generate the method returning the name of the variants
+fn generate_as_str(
+    data_enum: &DataEnum,
+    enum_ident: &Ident,
+    generics: &Generics,
+    args: &EnumRangeArgs,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let method_name = generated_name(
+        args.as_str_fn.as_deref(),
+        "as_str_fn",
+        format_ident!("as_str"),
+    )?;
+    let constness = args.const_fns.then(|| quote!(const));
+    let vis = item_vis(args.vis.as_ref());
+    let arms = data_enum.variants.iter().map(|variant| {
+        let cfgs = cfg_attrs(variant);
+        let pattern = variant_pattern(variant);
+        let name = variant.ident.unraw().to_string();
+        quote! {
+            #(#cfgs)*
+            #pattern => #name,
+        }
+    });
+
+    Ok(quote! {
+        impl #impl_generics #enum_ident #ty_generics #where_clause {
+            #vis #constness fn #method_name(&self) -> &'static str {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+    })
+}
+
+/// Generate the `FromStr` implementation parsing the names of the variants without fields
+fn generate_from_str(
+    data_enum: &DataEnum,
+    enum_ident: &Ident,
+    generics: &Generics,
+    vis: &Visibility,
+    args: &EnumRangeArgs,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let arms = data_enum
+        .variants
+        .iter()
+        .filter(|variant| matches!(variant.fields, Fields::Unit))
+        .map(|variant| {
+            let cfgs = cfg_attrs(variant);
+            let ident = &variant.ident;
+            let name = ident.unraw().to_string();
+            quote! {
+                #(#cfgs)*
+                #name => ::core::result::Result::Ok(Self::#ident),
+            }
+        });
+
+    let names = data_enum
+        .variants
+        .iter()
+        .filter(|variant| matches!(variant.fields, Fields::Unit))
+        .map(|variant| {
+            let cfgs = cfg_attrs(variant);
+            let name = variant.ident.unraw().to_string();
+            quote! {
+                #(#cfgs)*
+                #name
+            }
+        });
+
+    let error_ident = generated_name(
+        args.from_str_error_name.as_deref(),
+        "from_str_error_name",
+        format_ident!("{}FromStrError", enum_ident),
+    )?;
+    let parse_error_ident = generated_name(
+        args.parse_error_name.as_deref(),
+        "parse_error_name",
+        format_ident!("{}ParseError", enum_ident),
+    )?;
+    // `alloc` isn't in the extern prelude, it is imported under a name specific to the enum for `no_std` crates
+    let alloc = format_ident!("__enum_range_alloc_{}", enum_ident);
+    let error_impl = generate_error_impl(&error_ident);
+    let parse_error_impl = generate_error_impl(&parse_error_ident);
+    let error_doc = format!(
+        "Error returned when parsing a string which is not the name of a [{enum_ident}] variant"
+    );
+    let parse_error_doc = format!(
+        "Error returned when converting a string which is not the name of a [{enum_ident}] variant, along with the valid names"
+    );
+
+    Ok(quote! {
+        extern crate alloc as #alloc;
+
+        #[doc = #error_doc]
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        #vis struct #error_ident(pub #alloc::string::String);
+
+        impl ::core::fmt::Display for #error_ident {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::write!(f, "invalid {} variant name: {}", ::core::stringify!(#enum_ident), self.0)
+            }
+        }
+
+        #error_impl
+
+        impl #impl_generics ::core::str::FromStr for #enum_ident #ty_generics #where_clause {
+            type Err = #error_ident;
+
+            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                match s {
+                    #(#arms)*
+                    _ => ::core::result::Result::Err(#error_ident(#alloc::string::String::from(s))),
+                }
+            }
+        }
+
+        #[doc = #parse_error_doc]
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        #vis struct #parse_error_ident {
+            /// The string which is not the name of a variant
+            pub input: #alloc::string::String,
+            /// The names of the variants which can be parsed
+            pub valid_names: &'static [&'static str],
+        }
+
+        impl #parse_error_ident {
+            /// Number of valid names written by the `Display` implementation
+            const DISPLAYED_NAMES: usize = 8;
+        }
+
+        impl ::core::fmt::Display for #parse_error_ident {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::write!(f, "invalid {} variant name: {}", ::core::stringify!(#enum_ident), self.input)?;
+                for (index, name) in self.valid_names.iter().take(Self::DISPLAYED_NAMES).enumerate() {
+                    f.write_str(if index == 0 { ", expected one of " } else { ", " })?;
+                    f.write_str(name)?;
+                }
+                if self.valid_names.len() > Self::DISPLAYED_NAMES {
+                    ::core::write!(f, ", and {} more", self.valid_names.len() - Self::DISPLAYED_NAMES)?;
+                }
+                ::core::result::Result::Ok(())
+            }
+        }
+
+        #parse_error_impl
+
+        impl #impl_generics ::core::convert::TryFrom<&str> for #enum_ident #ty_generics #where_clause {
+            type Error = #parse_error_ident;
+
+            fn try_from(s: &str) -> ::core::result::Result<Self, Self::Error> {
+                ::core::str::FromStr::from_str(s).map_err(|_| #parse_error_ident {
+                    input: #alloc::string::String::from(s),
+                    valid_names: &[#(#names),*],
+                })
+            }
+        }
+    })
+}
+
+/// Generate the `Serialize` and `Deserialize` implementations using the discriminator values
+#[cfg(feature = "serde")]
+fn generate_serde(
+    data_enum: &DataEnum,
+    enum_ident: &Ident,
+    generics: &Generics,
+    repr: &Option<Ident>,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let repr = require_repr(repr, "serde")?;
+    let value = discriminant_of_self(data_enum, repr)?;
+    let arms = repr_match_arms(
+        data_enum,
+        repr,
+        |variant| quote!(::core::result::Result::Ok(#variant)),
+    )?;
+
+    // `Deserialize` takes the lifetime of the deserializer on top of the generics of the enum
+    let mut de_generics = generics.clone();
+    de_generics.params.insert(0, syn::parse_quote!('de));
+    let (de_impl_generics, _, _) = de_generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::serde::Serialize for #enum_ident #ty_generics #where_clause {
+            fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error> {
+                let value: #repr = #value;
+                ::serde::Serialize::serialize(&value, serializer)
+            }
+        }
+
+        impl #de_impl_generics ::serde::Deserialize<'de> for #enum_ident #ty_generics #where_clause {
+            fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> ::core::result::Result<Self, D::Error> {
+                let value = <#repr as ::serde::Deserialize<'de>>::deserialize(deserializer)?;
+                match value {
+                    #(#arms)*
+                    _ => ::core::result::Result::Err(<D::Error as ::serde::de::Error>::custom(::core::format_args!(
+                        "invalid {} discriminator: {}",
+                        ::core::stringify!(#enum_ident),
+                        value
+                    ))),
+                }
+            }
+        }
+    })
+}
+
+/// Gets the numerical repr of the enum required by an option
+fn require_repr<'a>(repr: &'a Option<Ident>, option: &str) -> syn::Result<&'a Ident> {
+    repr.as_ref().ok_or_else(|| {
+        syn::Error::new(
+            Span::call_site(),
+            format!("{option} requires the enum to have a numerical repr attribute"),
+        )
+    })
+}
+
+/// Generate the match arms converting a discriminator `value` to the variant it belongs to
+/// `wrap` builds the arm's result from the path to the variant
+/// Variants with fields are left out since they can't be built from their discriminator alone
+fn repr_match_arms(
+    data_enum: &DataEnum,
+    repr: &Ident,
+    wrap: impl Fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream,
+) -> syn::Result<Vec<proc_macro2::TokenStream>> {
+    let discriminants = discriminant_values(data_enum, repr)?;
+    Ok(data_enum
+        .variants
+        .iter()
+        .zip(discriminants)
+        .filter(|(variant, _)| matches!(variant.fields, Fields::Unit))
+        .map(|(variant, discriminant)| {
+            let cfgs = cfg_attrs(variant);
+            let ident = &variant.ident;
+            let result = wrap(quote!(Self::#ident));
+            quote! {
+                #(#cfgs)*
+                value if value == #discriminant => #result,
+            }
+        })
+        .collect())
+}
+
+/// Gets the identifier of a generated item, named by the given option or after the enum by default
+fn generated_name(name: Option<&str>, option: &str, default: Ident) -> syn::Result<Ident> {
+    match name {
+        Some(name) => syn::parse_str::<Ident>(name).map_err(|_| {
+            syn::Error::new(
+                Span::call_site(),
+                format!("{option} `{name}` is not a valid identifier"),
+            )
+        }),
+        None => Ok(default),
+    }
+}
+
+/// Generate the `Error` implementation of a generated error type: from `core` with the `core_error` feature
+/// (Rust 1.81), otherwise from `std` with the `std` feature, and none without either of them
+fn generate_error_impl(error_ident: &Ident) -> Option<proc_macro2::TokenStream> {
+    if cfg!(feature = "core_error") {
+        Some(quote!(impl ::core::error::Error for #error_ident {}))
+    } else if cfg!(feature = "std") {
+        Some(quote!(impl ::std::error::Error for #error_ident {}))
+    } else {
+        None
+    }
+}
+
+/// Gets the visibility of a generated item, which is public unless specified otherwise
+fn item_vis(vis: Option<&Visibility>) -> proc_macro2::TokenStream {
+    match vis {
+        Some(vis) => quote!(#vis),
+        None => quote!(pub),
+    }
+}
+
+/// Gets the `#[cfg(...)]` attributes of a variant, they must be repeated on any code naming the variant
+fn cfg_attrs(variant: &Variant) -> impl Iterator<Item = &Attribute> {
+    variant
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("cfg"))
+}
+
+/// Number of variants a range can generate when the `max_variants` option isn't given
+const DEFAULT_MAX_VARIANTS: usize = 4096;
+
+/// Format used for naming the variants of a range when none is specified
+const DEFAULT_FORMAT: &str = "{name}{index}";
+
+/// Widest zero-padding of an integer placeholder, enough for the binary digits of any repr
+const MAX_FORMAT_WIDTH: usize = 128;
+
+/// A range whose variants are being generated
+struct RangeContext<'a> {
+    /// The annotated variant replaced by the range
+    variant: &'a Variant,
+    range: &'a Range,
+    repr: &'a Option<Ident>,
+    /// The `#[range(...)]` attribute defining the range
+    attr: &'a Attribute,
+}
+
+/// Bounds of a range included by its `range_check`
+#[derive(Debug, Clone, Copy, FromMeta)]
+enum RangeCheckBounds {
+    Inclusive,
+    Exclusive,
+    InclusiveLower,
+    InclusiveUpper,
+}
+
+/// Radix of the discriminator values written by the generated `Debug`
+#[derive(Debug, Clone, Copy, FromMeta)]
+enum Radix {
+    Dec,
+    Hex,
+    Bin,
+}
+
+/// Case applied to the names of the variants of a range
+#[derive(Debug, Clone, Copy, FromMeta)]
+enum Case {
+    Upper,
+    Lower,
+    Pascal,
+    Snake,
+}
+
+impl Case {
+    fn apply(self, name: &str) -> String {
+        // The leading underscores are kept so that the name doesn't start with a digit once converted
+        let trimmed = name.trim_start_matches('_');
+        let leading = &name[..name.len() - trimmed.len()];
+        match self {
+            Case::Upper => name.to_uppercase(),
+            Case::Lower => name.to_lowercase(),
+            Case::Pascal => {
+                let words = Self::words(trimmed).into_iter().map(|word| {
+                    let mut chars = word.chars();
+                    let first = chars.next().into_iter().flat_map(char::to_uppercase);
+                    first
+                        .chain(chars.flat_map(char::to_lowercase))
+                        .collect::<String>()
+                });
+                format!("{leading}{}", words.collect::<String>())
+            }
+            Case::Snake => {
+                let words: Vec<String> = Self::words(trimmed)
+                    .into_iter()
+                    .map(|word| word.to_lowercase())
+                    .collect();
+                format!("{leading}{}", words.join("_"))
+            }
+        }
+    }
+
+    /// Splits a name on underscores and before the uppercase letters following a lowercase letter or a digit
+    fn words(name: &str) -> Vec<String> {
+        let mut words = vec![String::new()];
+        let mut previous: Option<char> = None;
+        for char in name.chars() {
+            if char == '_' {
+                words.push(String::new());
+            } else {
+                if char.is_uppercase()
+                    && previous.is_some_and(|previous| {
+                        previous.is_lowercase() || previous.is_ascii_digit()
+                    })
+                {
+                    words.push(String::new());
+                }
+                if let Some(word) = words.last_mut() {
+                    word.push(char);
+                }
+            }
+            previous = Some(char);
+        }
+        words.retain(|word| !word.is_empty());
+        words
+    }
+}
+
+/// Generate the [Ident] for an enum variant in a range
+fn generate_variant_ident(context: &RangeContext, index: usize, value: i128) -> syn::Result<Ident> {
+    let range = context.range;
+    let mut ident_str = match range.names.as_ref().and_then(|names| names.get(index)) {
+        Some(name) => name.value(),
+        None => {
+            let default_format = if range.prefix.is_none() && range.suffix.is_none() {
+                DEFAULT_FORMAT
+            } else if range.base().is_some() {
+                "{index}"
+            } else {
+                "{value}"
+            };
+            let format = range.format.as_deref().unwrap_or(default_format);
+            // `-` isn't allowed in identifiers, the negative values are prefixed with `M` (minus) in the names
+            let name = format_placeholders(context, format, index, value, "M")?;
+            format!(
+                "{}{name}{}",
+                range.prefix.as_deref().unwrap_or_default(),
+                range.suffix.as_deref().unwrap_or_default()
+            )
+        }
+    };
+    if let Some(case) = range.case {
+        ident_str = case.apply(&ident_str);
+    }
+
+    // `Ident::new` panics on invalid identifiers, they are reported on the attribute instead.
+    // A name made of identifier characters that doesn't parse is a keyword, which is valid once raw
+    // (e.g. `r#type`) except for the ones naming paths
+    let can_be_raw = ident_str
+        .starts_with(|char: char| unicode_ident::is_xid_start(char) || char == '_')
+        && ident_str.chars().all(unicode_ident::is_xid_continue)
+        && !matches!(
+            ident_str.as_str(),
+            "_" | "self" | "Self" | "super" | "crate"
+        );
+    let mut ident = syn::parse_str::<Ident>(&ident_str)
+        .ok()
+        .or_else(|| can_be_raw.then(|| Ident::new_raw(&ident_str, Span::call_site())))
+        .ok_or_else(|| {
+            let reason = if ident_str.starts_with(|char: char| char.is_ascii_digit()) {
+                ", it starts with a digit"
+            } else {
+                ""
+            };
+            syn::Error::new_spanned(
+                context.attr,
+                format!("range variant name `{ident_str}` is not a valid identifier{reason}"),
+            )
+        })?;
+    // The diagnostics about a generated variant point at the annotated variant
+    ident.set_span(context.variant.ident.span());
+
+    Ok(ident)
+}
+
+/// Generate the attributes of an enum variant in a range
+/// The docs of the annotated variant are replaced by the range `doc` template if there is one
+fn generate_variant_attrs(
+    context: &RangeContext,
+    index: usize,
+    value: i128,
+) -> syn::Result<Vec<Attribute>> {
+    let variant = context.variant;
+    let Some(doc) = &context.range.doc else {
+        return Ok(variant.attrs.clone());
+    };
+
+    let doc = format_placeholders(context, doc, index, value, "-")?;
+    let mut attrs: Vec<Attribute> = variant
+        .attrs
+        .iter()
+        .filter(|attr| !attr.path().is_ident("doc"))
+        .cloned()
+        .collect();
+    attrs.push(syn::parse_quote!(#[doc = #doc]));
+
+    Ok(attrs)
+}
+
+/// Checks if a name is in camel case as the `non_camel_case_types` lint of rustc expects it: without a leading
+/// lowercase letter, a double underscore, or an underscore next to a letter
+fn is_camel_case(name: &str) -> bool {
+    let name = name.trim_matches('_');
+    let has_case = |char: char| char.is_lowercase() || char.is_uppercase();
+    !name.starts_with(char::is_lowercase)
+        && !name.contains("__")
+        && !name
+            .chars()
+            .zip(name.chars().skip(1))
+            .any(|(first, second)| {
+                (has_case(first) && second == '_') || (first == '_' && has_case(second))
+            })
+}
+
+/// Replaces the `{name}`, `{index}`, `{value}`, `{parity}` and `{repr}` placeholders of a format,
+/// the negative numbers are written with the given sign before their digits
+fn format_placeholders(
+    context: &RangeContext,
+    format: &str,
+    index: usize,
+    value: i128,
+    negative_sign: &str,
+) -> syn::Result<String> {
+    let RangeContext {
+        variant,
+        range,
+        repr,
+        attr,
+    } = context;
+
+    let mut formatted_str = String::new();
+    let mut rest = format;
+    while let Some(open) = rest.find('{') {
+        let Some(close) = rest[open..].find('}').map(|close| open + close) else {
+            break;
+        };
+
+        formatted_str.push_str(&rest[..open]);
+
+        let placeholder = &rest[open + 1..close];
+        let (key, spec) = placeholder.split_once(':').unwrap_or((placeholder, ""));
+        // the named radix placeholders, e.g. `{value_hex}`, are written with their radix spec, `{value:x}`
+        let named_radix = [
+            ("_hex_upper", "X"),
+            ("_hex", "x"),
+            ("_oct", "o"),
+            ("_bin", "b"),
+        ]
+        .into_iter()
+        .find_map(|(suffix, radix)| {
+            let key = key.strip_suffix(suffix)?;
+            matches!(key, "index" | "value").then_some((key, radix))
+        });
+        let spec = match named_radix {
+            Some((_, radix)) => format!("{spec}{radix}"),
+            None => spec.to_string(),
+        };
+        let (key, spec) = (named_radix.map_or(key, |(key, _)| key), spec.as_str());
+        match key {
+            "name" if spec.is_empty() => formatted_str.push_str(&variant.ident.unraw().to_string()),
+            "repr" if spec.is_empty() => {
+                let repr = repr.as_ref().ok_or_else(|| {
+                    syn::Error::new_spanned(
+                        attr,
+                        "the {repr} placeholder requires the enum to have a numerical #[repr(...)] attribute",
+                    )
+                })?;
+                formatted_str.push_str(&repr.to_string());
+            }
+            // the computed value only depends on the index
+            "value" if range.is_value_scaled() => {
+                let value = range
+                    .scaled_value(index)
+                    .ok_or_else(|| syn::Error::new_spanned(attr, "range value_base + index * value_scale overflows"))?;
+                let formatted = format_integer(value, spec, negative_sign)
+                    .map_err(|message| syn::Error::new_spanned(attr, message))?;
+                formatted_str.push_str(&formatted);
+            }
+            "value" | "parity" if range.base().is_some() => {
+                return Err(syn::Error::new_spanned(
+                    attr,
+                    format!("the {{{key}}} placeholder can't be used in a range relative to a constant, its value is only known by the compiler"),
+                ))
+            }
+            "parity" if spec.is_empty() => {
+                formatted_str.push_str(if value % 2 == 0 { "Even" } else { "Odd" })
+            }
+            // the values of a character range are written as characters, unless a spec is given
+            "value" if spec.is_empty() && range.first_bound().is_char => {
+                let char = u32::try_from(value)
+                    .ok()
+                    .and_then(char::from_u32)
+                    .ok_or_else(|| {
+                        syn::Error::new_spanned(
+                            attr,
+                            format!("range value {value:#x} is not a valid character"),
+                        )
+                    })?;
+                formatted_str.push(char);
+            }
+            "index" | "value" => {
+                let value = if key == "index" {
+                    index as i128 + range.index_offset.unwrap_or(0) as i128
+                } else {
+                    value
+                };
+                let formatted = format_integer(value, spec, negative_sign)
+                    .map_err(|message| syn::Error::new_spanned(attr, message))?;
+                formatted_str.push_str(&formatted);
+            }
+            // not a placeholder, keep it as is
+            _ => formatted_str.push_str(&rest[open..=close]),
+        }
+
+        rest = &rest[close + 1..];
+    }
+    formatted_str.push_str(rest);
+
+    Ok(formatted_str)
+}
+
+/// Formats an integer placeholder according to its format spec `[0width][radix]`
+/// (`0width`: zero-padded to `width` digits, radix `x`: lowercase hex, `X`: uppercase hex, `o`: octal, `b`: binary, none: decimal)
+/// The digits of a negative value are the ones of its magnitude, preceded by `negative_sign`
+fn format_integer(value: i128, spec: &str, negative_sign: &str) -> Result<String, String> {
+    let width_len = spec
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(spec.len());
+    let (width, radix) = spec.split_at(width_len);
+
+    let width = match width {
+        "" => 0,
+        _ if width.starts_with('0') => match width.parse::<usize>() {
+            Ok(width) if width <= MAX_FORMAT_WIDTH => width,
+            _ => {
+                return Err(format!(
+                "unsupported format spec `{spec}`, the width can't be more than {MAX_FORMAT_WIDTH}"
+            ))
+            }
+        },
+        _ => {
+            return Err(format!(
+                "unsupported format spec `{spec}`, the width must start with a 0 (e.g. `03`)"
+            ))
+        }
+    };
+
+    let sign = if value < 0 { negative_sign } else { "" };
+    let magnitude = value.unsigned_abs();
+    let digits = match radix {
+        "" => format!("{magnitude:0width$}"),
+        "x" => format!("{magnitude:0width$x}"),
+        "X" => format!("{magnitude:0width$X}"),
+        "o" => format!("{magnitude:0width$o}"),
+        "b" => format!("{magnitude:0width$b}"),
+        _ => {
+            return Err(format!(
+                "unsupported format spec `{spec}`, expected one of `x`, `X`, `o` or `b`"
+            ))
+        }
+    };
+    Ok(format!("{sign}{digits}"))
 }
@@ -1,4 +1,4 @@
-#[enum_range]
+#[enum_range(from_repr, try_from, display, serde)]
 #[repr(u16)]
 #[derive(Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq)]
 enum RangedEnum {
@@ -35,12 +35,30 @@ use enum_range::enum_range;
 
 fn main() {
     let zero = RangedEnum::Zero;
-    println!("RangedEnum::Zero is_well_known => {:?}", RangedEnum::is_well_known(zero));
-    println!("RangedEnum::Zero is_unassigned => {:?}", RangedEnum::is_unassigned(zero));
-    println!("RangedEnum::Zero is_private_use => {:?}", RangedEnum::is_private_use(zero));
+    println!("RangedEnum::Zero is_well_known => {:?}", zero.is_well_known());
+    println!("RangedEnum::Zero is_unassigned => {:?}", zero.is_unassigned());
+    println!("RangedEnum::Zero is_private_use => {:?}", zero.is_private_use());
 
     let pu10 = RangedEnum::PrivateUse0_10;
-    println!("RangedEnum::PrivateUse0_10 is_well_known => {:?}", RangedEnum::is_well_known(pu10));
-    println!("RangedEnum::PrivateUse0_10 is_unassigned => {:?}", RangedEnum::is_unassigned(pu10));
-    println!("RangedEnum::PrivateUse0_10 is_private_use => {:?}", RangedEnum::is_private_use(pu10));
+    println!("RangedEnum::PrivateUse0_10 is_well_known => {:?}", pu10.is_well_known());
+    println!("RangedEnum::PrivateUse0_10 is_unassigned => {:?}", pu10.is_unassigned());
+    println!("RangedEnum::PrivateUse0_10 is_private_use => {:?}", pu10.is_private_use());
+
+    println!("RangedEnum::from_repr(207) => {:?}", RangedEnum::from_repr(207));
+    println!("RangedEnum::from_repr(100) => {:?}", RangedEnum::from_repr(100));
+    println!("RangedEnum::try_from(400) => {:?}", RangedEnum::try_from(400));
+    println!("RangedEnum::try_from(100) => {:?}", RangedEnum::try_from(100));
+
+    println!("RangedEnum::WellKnown2 displays as {}", RangedEnum::WellKnown2);
+
+    let json = serde_json::to_string(&RangedEnum::Unassigned201).unwrap();
+    println!("RangedEnum::Unassigned201 serializes as {json}");
+    println!(
+        "{json} deserializes as {:?}",
+        serde_json::from_str::<RangedEnum>(&json).unwrap()
+    );
+    println!(
+        "10000 deserializes as {:?}",
+        serde_json::from_str::<RangedEnum>("10000")
+    );
 }
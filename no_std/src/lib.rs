@@ -0,0 +1,26 @@
+//! Checks that the code generated by `#[enum_range]` builds in a `no_std` crate
+#![no_std]
+
+use enum_range::enum_range;
+
+#[enum_range(from_repr, try_from, from_str, as_str, display, const_fns)]
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Register {
+    Status = 0,
+    Control = 1,
+    #[range(format = "Data{index}", start = 0x10, end = 0x17, range_check = "is_data")]
+    Data,
+    #[range(format = "Vendor{index}", start = 0xF0, end = 0xF3, range_check = "is_vendor")]
+    Vendor,
+}
+
+/// Decodes a register address, keeping only the data registers
+pub fn decode_data(address: u8) -> Option<Register> {
+    Register::from_repr(address).filter(Register::is_data)
+}
+
+/// Parses the name of a register
+pub fn parse(name: &str) -> Option<Register> {
+    name.parse().ok()
+}